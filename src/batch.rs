@@ -0,0 +1,220 @@
+//! Conversions of many points at once
+
+#[cfg(all(feature = "alloc", feature = "libm"))]
+use crate::Bounds;
+use crate::{Lv03, Lv95, Wgs84};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+impl Wgs84 {
+    /// Converts all points to LV95 and writes them to `out`, none for points outside of the
+    /// swiss coordinate system. Does not allocate.
+    /// Panics if `points` and `out` do not have the same length
+    pub fn batch_to_lv95(points: &[Wgs84], out: &mut [Option<Lv95>]) {
+        assert_eq!(points.len(), out.len(), "length mismatch");
+        for (p, lv95) in points.iter().zip(out.iter_mut()) {
+            *lv95 = p.to_lv95();
+        }
+    }
+}
+
+/// Converts all points to WGS84 and returns the sum of their latitudes.
+///
+/// This is a benchmarking and verification aid rather than a general API: the optimizer can
+/// not discard conversions whose result is used, and a known input gives a known sum
+pub fn batch_to_wgs84_checksum(points: &[Lv03]) -> f64 {
+    points.iter().map(|p| p.to_wgs84().latitude).sum()
+}
+
+/// Writes the `Lv03::roundtrip_error` of each point to `out`, e.g. to map the accuracy of the
+/// conversion over a dataset. Does not allocate.
+/// Panics if `points` and `out` do not have the same length
+#[cfg(feature = "libm")]
+pub fn batch_roundtrip_errors(points: &[Lv03], out: &mut [f64]) {
+    assert_eq!(points.len(), out.len(), "length mismatch");
+    for (p, error) in points.iter().zip(out.iter_mut()) {
+        *error = p.roundtrip_error();
+    }
+}
+
+/// Result of `convert_dataset_to_wgs84`
+#[cfg(all(feature = "alloc", feature = "libm"))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DatasetConversion {
+    /// The converted points in the same order as the input, including the points out of domain
+    pub results: Vec<Wgs84>,
+    /// Largest `Lv03::roundtrip_error` of the points within the domain
+    pub max_roundtrip_error_m: f64,
+    /// Number of points rejected by the checks of `Lv03::new`, their conversion is extrapolated
+    pub out_of_domain_count: usize,
+}
+
+/// Converts all points to WGS84 and reports the quality of the conversion, e.g. for an import
+/// report. Points outside of the domain are converted as well, but only counted and not
+/// considered for the roundtrip error
+#[cfg(all(feature = "alloc", feature = "libm"))]
+pub fn convert_dataset_to_wgs84(points: &[Lv03]) -> DatasetConversion {
+    let mut results = Vec::with_capacity(points.len());
+    let mut max_roundtrip_error_m = 0.0f64;
+    let mut out_of_domain_count = 0;
+    for p in points {
+        let wgs84 = p.to_wgs84();
+        if Lv03::is_valid(p.north, p.east, &Bounds::SWITZERLAND) {
+            max_roundtrip_error_m = max_roundtrip_error_m.max(p.roundtrip_error_of(&wgs84));
+        } else {
+            out_of_domain_count += 1;
+        }
+        results.push(wgs84);
+    }
+    DatasetConversion {
+        results,
+        max_roundtrip_error_m,
+        out_of_domain_count,
+    }
+}
+
+/// Converts all points to LV03. Returns the converted points and, separately,
+/// the original points which are outside of the swiss coordinate system
+#[cfg(feature = "alloc")]
+pub fn partition_to_lv03<I: IntoIterator<Item = Wgs84>>(iter: I) -> (Vec<Lv03>, Vec<Wgs84>) {
+    let mut converted = Vec::new();
+    let mut rejected = Vec::new();
+    for p in iter {
+        match p.to_lv03() {
+            Some(lv03) => converted.push(lv03),
+            None => rejected.push(p),
+        }
+    }
+    (converted, rejected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_batch_to_lv95() {
+        let points = [
+            Wgs84 {
+                longitude: 7.44417,
+                latitude: 46.94658,
+                altitude: Some(591.8),
+            },
+            Wgs84 {
+                longitude: 2.3522,
+                latitude: 48.8566,
+                altitude: None,
+            },
+        ];
+        let mut out = [None, None];
+        Wgs84::batch_to_lv95(&points, &mut out);
+        assert_eq!(points[0].to_lv95(), out[0]);
+        assert_eq!(None, out[1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_batch_to_lv95_length_mismatch() {
+        Wgs84::batch_to_lv95(&[], &mut [None]);
+    }
+
+    #[test]
+    fn test_batch_to_wgs84_checksum() {
+        let points = [
+            Lv03::new(199_498.43, 600_421.43, 542.8).unwrap(),
+            Lv03::new_2d(91_673.72, 617_049.89).unwrap(),
+        ];
+        let expected = points[0].to_wgs84().latitude + points[1].to_wgs84().latitude;
+        assert_eq!(expected, batch_to_wgs84_checksum(&points));
+        assert!((batch_to_wgs84_checksum(&points) - 92.923).abs() < 0.001);
+        assert_eq!(0.0, batch_to_wgs84_checksum(&[]));
+    }
+
+    #[test]
+    #[cfg(feature = "libm")]
+    fn test_batch_roundtrip_errors() {
+        let points = [
+            Lv03::new(199_498.43, 600_421.43, 542.8).unwrap(),
+            Lv03::new_2d(91_673.72, 617_049.89).unwrap(),
+            Lv03::new_2d(70_000.0, 850_000.0).unwrap(),
+        ];
+        let mut out = [0.0; 3];
+        batch_roundtrip_errors(&points, &mut out);
+        for (p, error) in points.iter().zip(out) {
+            assert_eq!(p.roundtrip_error(), error);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(feature = "libm")]
+    fn test_batch_roundtrip_errors_length_mismatch() {
+        batch_roundtrip_errors(&[], &mut [0.0]);
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "libm"))]
+    fn test_convert_dataset_to_wgs84() {
+        let bundeshaus = Lv03::new(199_498.43, 600_421.43, 542.8).unwrap();
+        let matterhorn = Lv03::new(91_673.72, 617_049.89, 4477.4).unwrap();
+        let swapped = Lv03 {
+            north: 600_421.43,
+            east: 199_498.43,
+            altitude: None,
+        };
+        let far_east = Lv03 {
+            north: 200_000.0,
+            east: 1_000_000.0,
+            altitude: None,
+        };
+        let points = [bundeshaus.clone(), swapped, matterhorn.clone(), far_east];
+        let conversion = convert_dataset_to_wgs84(&points);
+        assert_eq!(4, conversion.results.len());
+        assert_eq!(bundeshaus.to_wgs84(), conversion.results[0]);
+        assert_eq!(matterhorn.to_wgs84(), conversion.results[2]);
+        assert_eq!(2, conversion.out_of_domain_count);
+        let rejected_by_new = points
+            .iter()
+            .filter(|p| Lv03::new_2d(p.north, p.east).is_none())
+            .count();
+        assert_eq!(rejected_by_new, conversion.out_of_domain_count);
+        assert_eq!(
+            bundeshaus
+                .roundtrip_error()
+                .max(matterhorn.roundtrip_error()),
+            conversion.max_roundtrip_error_m
+        );
+        assert!(conversion.max_roundtrip_error_m < 5.0);
+
+        let empty = convert_dataset_to_wgs84(&[]);
+        assert!(empty.results.is_empty());
+        assert_eq!(0.0, empty.max_roundtrip_error_m);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_partition_to_lv03() {
+        let bundeshaus = Wgs84 {
+            longitude: 7.44417,
+            latitude: 46.94658,
+            altitude: Some(591.8),
+        };
+        let matterhorn = Wgs84 {
+            longitude: 7.65861,
+            latitude: 45.97642,
+            altitude: Some(4532.9),
+        };
+        let paris = Wgs84 {
+            longitude: 2.3522,
+            latitude: 48.8566,
+            altitude: None,
+        };
+        let (converted, rejected) =
+            partition_to_lv03([bundeshaus.clone(), paris.clone(), matterhorn.clone()]);
+        assert_eq!(
+            [bundeshaus.to_lv03().unwrap(), matterhorn.to_lv03().unwrap()][..],
+            converted[..]
+        );
+        assert_eq!([paris][..], rejected[..]);
+    }
+}
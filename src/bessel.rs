@@ -0,0 +1,166 @@
+//! Rigorous formulas of the swiss projection on the Bessel 1841 ellipsoid, see the swisstopo document
+//! "Formeln und Konstanten für die Berechnung der Schweizerischen schiefachsigen Zylinderprojektion"
+
+use crate::Lv03;
+use core::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+/// Semi-major axis of the Bessel 1841 ellipsoid in meters
+const BESSEL_A: f64 = 6_377_397.155;
+/// First eccentricity squared of the Bessel 1841 ellipsoid
+const BESSEL_E2: f64 = 0.006_674_372_230_614;
+/// Longitude of the fundamental point in Bern in degrees (7° 26' 22.50")
+const LAMBDA_0: f64 = 7.0 + 26.0 / 60.0 + 22.50 / 3600.0;
+/// Radius of the projection sphere
+const RADIUS: f64 = 6_378_815.903_65;
+/// Ratio between sphere and ellipsoid longitudes
+const ALPHA: f64 = 1.000_729_138_430_38;
+/// Latitude of the fundamental point on the sphere in degrees (46° 54' 27.83324844")
+const B_0: f64 = 46.0 + 54.0 / 60.0 + 27.833_248_44 / 3600.0;
+/// Integration constant of the latitude mapping
+const K: f64 = 0.003_066_732_377_275_1;
+/// Semi-major axis of the WGS84 ellipsoid in meters
+const WGS84_A: f64 = 6_378_137.0;
+/// First eccentricity squared of the WGS84 ellipsoid
+const WGS84_E2: f64 = 0.006_694_379_990_14;
+/// Geocentric translation from CH1903 to WGS84 in meters, the 3 parameter shift published by swisstopo
+const DATUM_SHIFT: (f64, f64, f64) = (674.374, 15.056, 405.346);
+
+impl Lv03 {
+    /// Geographic coordinates `(latitude, longitude)` in degrees on the Bessel 1841 ellipsoid (CH1903).
+    ///
+    /// These are *not* WGS84 coordinates: they are the result of the rigorous inverse projection
+    /// before the datum shift to WGS84. Within Switzerland they differ from WGS84 by
+    /// roughly 0.0013° in latitude and 0.001° in longitude
+    pub fn to_bessel_geographic(&self) -> (f64, f64) {
+        let e = libm::sqrt(BESSEL_E2);
+        let y = self.east - 600_000.0;
+        let x = self.north - 200_000.0;
+        let b_0 = B_0.to_radians();
+
+        // Oblique spherical coordinates
+        let l_oblique = y / RADIUS;
+        let b_oblique = 2.0 * (libm::atan(libm::exp(x / RADIUS)) - FRAC_PI_4);
+        // Spherical coordinates relative to the equator
+        let b = libm::asin(
+            libm::cos(b_0) * libm::sin(b_oblique)
+                + libm::sin(b_0) * libm::cos(b_oblique) * libm::cos(l_oblique),
+        );
+        let l = libm::atan(
+            libm::sin(l_oblique)
+                / (libm::cos(b_0) * libm::cos(l_oblique) - libm::sin(b_0) * libm::tan(b_oblique)),
+        );
+
+        let longitude = LAMBDA_0 + (l / ALPHA).to_degrees();
+        // Iterate the ellipsoidal latitude, converges within a few steps
+        let sphere_term = (libm::log(libm::tan(FRAC_PI_4 + b / 2.0)) - K) / ALPHA;
+        let mut phi = b;
+        for _ in 0..10 {
+            let s = sphere_term
+                + e * libm::log(libm::tan(FRAC_PI_4 + libm::asin(e * libm::sin(phi)) / 2.0));
+            let next = 2.0 * libm::atan(libm::exp(s)) - FRAC_PI_2;
+            let converged = (next - phi).abs() < 1e-14;
+            phi = next;
+            if converged {
+                break;
+            }
+        }
+        (phi.to_degrees(), longitude)
+    }
+}
+
+/// Shifts geographic coordinates on the Bessel 1841 ellipsoid (CH1903) to WGS84 and returns
+/// `(latitude, longitude, height)`. Angles are in degrees, `h` and the returned height are
+/// ellipsoidal heights in meters.
+///
+/// The shift is a pure geocentric translation of dX = 674.374 m, dY = 15.056 m, dZ = 405.346 m
+/// without rotation or scale, as published by swisstopo. It is accurate to about a meter,
+/// the same as the swiss datum itself. Together with `Lv03::to_bessel_geographic` it forms
+/// the rigorous conversion from LV03 to WGS84
+pub fn bessel_to_wgs84_datum_shift(lat_bessel: f64, lon_bessel: f64, h: f64) -> (f64, f64, f64) {
+    let (x, y, z) = to_cartesian(lat_bessel, lon_bessel, h, BESSEL_A, BESSEL_E2);
+    from_cartesian(
+        x + DATUM_SHIFT.0,
+        y + DATUM_SHIFT.1,
+        z + DATUM_SHIFT.2,
+        WGS84_A,
+        WGS84_E2,
+    )
+}
+
+/// Geocentric cartesian coordinates of a point on the given ellipsoid
+fn to_cartesian(latitude: f64, longitude: f64, h: f64, a: f64, e2: f64) -> (f64, f64, f64) {
+    let (phi, lambda) = (latitude.to_radians(), longitude.to_radians());
+    let n = a / libm::sqrt(1.0 - e2 * libm::sin(phi) * libm::sin(phi));
+    (
+        (n + h) * libm::cos(phi) * libm::cos(lambda),
+        (n + h) * libm::cos(phi) * libm::sin(lambda),
+        (n * (1.0 - e2) + h) * libm::sin(phi),
+    )
+}
+
+/// Inverse of `to_cartesian`, iterates the latitude
+fn from_cartesian(x: f64, y: f64, z: f64, a: f64, e2: f64) -> (f64, f64, f64) {
+    let p = libm::sqrt(x * x + y * y);
+    let lambda = libm::atan2(y, x);
+    let mut phi = libm::atan2(z, p * (1.0 - e2));
+    let mut h = 0.0;
+    for _ in 0..10 {
+        let n = a / libm::sqrt(1.0 - e2 * libm::sin(phi) * libm::sin(phi));
+        h = p / libm::cos(phi) - n;
+        let next = libm::atan2(z, p * (1.0 - e2 * n / (n + h)));
+        let converged = (next - phi).abs() < 1e-14;
+        phi = next;
+        if converged {
+            break;
+        }
+    }
+    (phi.to_degrees(), lambda.to_degrees(), h)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fundamental_point() {
+        let bern = Lv03::new(200_000.0, 600_000.0, 500.0).unwrap();
+        let (latitude, longitude) = bern.to_bessel_geographic();
+        assert!((latitude - (46.0 + 57.0 / 60.0 + 8.66 / 3600.0)).abs() < 1e-9);
+        assert!((longitude - LAMBDA_0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_bundeshaus() {
+        let lv = Lv03::new(199_498.43, 600_421.43, 542.8).unwrap();
+        let (latitude, longitude) = lv.to_bessel_geographic();
+        assert!((latitude - 46.947_893_17).abs() < 1e-7);
+        assert!((longitude - 7.445_119_67).abs() < 1e-7);
+
+        // Differs from WGS84 by the datum shift
+        let wgs = lv.to_wgs84();
+        assert!((latitude - wgs.latitude - 0.0013).abs() < 0.0002);
+        assert!((longitude - wgs.longitude - 0.00095).abs() < 0.0002);
+    }
+
+    #[test]
+    fn test_datum_shift() {
+        let lv = Lv03::new(199_498.43, 600_421.43, 542.8).unwrap();
+        let (latitude, longitude) = lv.to_bessel_geographic();
+        let (latitude, longitude, height) = bessel_to_wgs84_datum_shift(latitude, longitude, 542.8);
+
+        // Agrees with the approximate formulas within their accuracy of about a meter
+        let wgs = lv.to_wgs84();
+        assert!((latitude - wgs.latitude).abs() < 2e-5);
+        assert!((longitude - wgs.longitude).abs() < 2e-5);
+        assert!((height - wgs.altitude.unwrap()).abs() < 3.0);
+    }
+
+    #[test]
+    fn test_cartesian_roundtrip() {
+        let (x, y, z) = to_cartesian(46.95, 7.44, 1000.0, WGS84_A, WGS84_E2);
+        let (latitude, longitude, h) = from_cartesian(x, y, z, WGS84_A, WGS84_E2);
+        assert!((latitude - 46.95).abs() < 1e-12);
+        assert!((longitude - 7.44).abs() < 1e-12);
+        assert!((h - 1000.0).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,215 @@
+use crate::{Bounds, Lv03};
+
+/// Axis aligned rectangle on the LV03 grid
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lv03BoundingBox {
+    pub min_north: f64,
+    pub max_north: f64,
+    pub min_east: f64,
+    pub max_east: f64,
+}
+
+impl Lv03BoundingBox {
+    /// Smallest box containing all the points, none for an empty slice
+    pub fn from_points(points: &[Lv03]) -> Option<Self> {
+        let (first, rest) = points.split_first()?;
+        let mut bounds = Lv03BoundingBox::from_point(first);
+        for p in rest {
+            bounds.include(p);
+        }
+        Some(bounds)
+    }
+
+    /// Box with zero extent at the point
+    pub(crate) fn from_point(p: &Lv03) -> Self {
+        Lv03BoundingBox {
+            min_north: p.north,
+            max_north: p.north,
+            min_east: p.east,
+            max_east: p.east,
+        }
+    }
+
+    /// Grows the box to contain the point
+    pub(crate) fn include(&mut self, p: &Lv03) {
+        self.min_north = self.min_north.min(p.north);
+        self.max_north = self.max_north.max(p.north);
+        self.min_east = self.min_east.min(p.east);
+        self.max_east = self.max_east.max(p.east);
+    }
+
+    /// Whether the point lies within the box, edges included
+    pub fn contains(&self, p: &Lv03) -> bool {
+        (self.min_north..=self.max_north).contains(&p.north)
+            && (self.min_east..=self.max_east).contains(&p.east)
+    }
+
+    /// Shortest horizontal distance in meters from the point to the box, 0 if it lies inside
+    #[cfg(feature = "libm")]
+    pub fn min_distance_to(&self, p: &Lv03) -> f64 {
+        let d_north = (self.min_north - p.north)
+            .max(p.north - self.max_north)
+            .max(0.0);
+        let d_east = (self.min_east - p.east)
+            .max(p.east - self.max_east)
+            .max(0.0);
+        libm::hypot(d_north, d_east)
+    }
+
+    /// Center of the box, without altitude
+    pub fn center(&self) -> Lv03 {
+        Lv03 {
+            north: (self.min_north + self.max_north) / 2.0,
+            east: (self.min_east + self.max_east) / 2.0,
+            altitude: None,
+        }
+    }
+
+    /// Smallest circle around the center covering the whole box as `(center, radius_m)`,
+    /// for queries which only accept a center and a radius. The center has no altitude
+    #[cfg(feature = "libm")]
+    pub fn bounding_radius(&self) -> (Lv03, f64) {
+        let center = self.center();
+        let corner = Lv03 {
+            north: self.max_north,
+            east: self.max_east,
+            altitude: None,
+        };
+        let radius = center.distance_2d(&corner);
+        (center, radius)
+    }
+
+    /// Box with each edge moved outwards by the margin and clamped to `Bounds::SWITZERLAND`.
+    /// A negative margin shrinks the box, none is returned if the box would invert
+    pub fn expand(&self, margin_m: f64) -> Option<Lv03BoundingBox> {
+        let (north, east) = (Bounds::SWITZERLAND.north, Bounds::SWITZERLAND.east);
+        let expanded = Lv03BoundingBox {
+            min_north: (self.min_north - margin_m).max(*north.start()),
+            max_north: (self.max_north + margin_m).min(*north.end()),
+            min_east: (self.min_east - margin_m).max(*east.start()),
+            max_east: (self.max_east + margin_m).min(*east.end()),
+        };
+        if expanded.min_north <= expanded.max_north && expanded.min_east <= expanded.max_east {
+            Some(expanded)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_points() {
+        let points = [
+            Lv03::new(200_000.0, 600_000.0, 500.0).unwrap(),
+            Lv03::new(210_000.0, 590_000.0, 500.0).unwrap(),
+            Lv03::new(190_000.0, 620_000.0, 500.0).unwrap(),
+        ];
+        let bounds = Lv03BoundingBox::from_points(&points).unwrap();
+        assert_eq!(
+            Lv03BoundingBox {
+                min_north: 190_000.0,
+                max_north: 210_000.0,
+                min_east: 590_000.0,
+                max_east: 620_000.0,
+            },
+            bounds
+        );
+        assert!(points.iter().all(|p| bounds.contains(p)));
+        assert!(!bounds.contains(&Lv03::new(211_000.0, 600_000.0, 500.0).unwrap()));
+        assert_eq!(Lv03::new_2d(200_000.0, 605_000.0).unwrap(), bounds.center());
+        assert_eq!(None, Lv03BoundingBox::from_points(&[]));
+    }
+
+    #[test]
+    #[cfg(feature = "libm")]
+    fn test_min_distance_to() {
+        let bounds = Lv03BoundingBox {
+            min_north: 190_000.0,
+            max_north: 210_000.0,
+            min_east: 590_000.0,
+            max_east: 620_000.0,
+        };
+        let distance = |north, east| bounds.min_distance_to(&Lv03::new_2d(north, east).unwrap());
+        assert_eq!(0.0, distance(200_000.0, 600_000.0));
+        assert_eq!(0.0, distance(210_000.0, 620_000.0));
+        assert_eq!(1000.0, distance(211_000.0, 600_000.0));
+        assert_eq!(2000.0, distance(188_000.0, 600_000.0));
+        assert_eq!(3000.0, distance(200_000.0, 623_000.0));
+        assert_eq!(4000.0, distance(200_000.0, 586_000.0));
+        // Closest to the north east corner
+        assert_eq!(5000.0, distance(213_000.0, 624_000.0));
+    }
+
+    #[test]
+    #[cfg(feature = "libm")]
+    fn test_bounding_radius() {
+        let square = Lv03BoundingBox {
+            min_north: 190_000.0,
+            max_north: 210_000.0,
+            min_east: 590_000.0,
+            max_east: 610_000.0,
+        };
+        let (center, radius) = square.bounding_radius();
+        assert_eq!(Lv03::new_2d(200_000.0, 600_000.0).unwrap(), center);
+        assert!((radius - 20_000.0 * core::f64::consts::SQRT_2 / 2.0).abs() < 1e-9);
+
+        let point = Lv03BoundingBox {
+            min_north: 200_000.0,
+            max_north: 200_000.0,
+            min_east: 600_000.0,
+            max_east: 600_000.0,
+        };
+        assert_eq!(0.0, point.bounding_radius().1);
+    }
+
+    #[test]
+    fn test_expand() {
+        let bounds = Lv03BoundingBox {
+            min_north: 190_000.0,
+            max_north: 210_000.0,
+            min_east: 590_000.0,
+            max_east: 620_000.0,
+        };
+        assert_eq!(
+            Some(Lv03BoundingBox {
+                min_north: 189_000.0,
+                max_north: 211_000.0,
+                min_east: 589_000.0,
+                max_east: 621_000.0,
+            }),
+            bounds.expand(1000.0)
+        );
+        assert_eq!(
+            Some(Lv03BoundingBox {
+                min_north: 199_000.0,
+                max_north: 201_000.0,
+                min_east: 599_000.0,
+                max_east: 611_000.0,
+            }),
+            bounds.expand(-9000.0)
+        );
+        assert_eq!(None, bounds.expand(-10_001.0));
+        assert_eq!(Some(bounds.clone()), bounds.expand(0.0));
+
+        // Clamped to the swiss coordinate system
+        let border = Lv03BoundingBox {
+            min_north: 75_000.0,
+            max_north: 80_000.0,
+            min_east: 840_000.0,
+            max_east: 845_000.0,
+        };
+        assert_eq!(
+            Some(Lv03BoundingBox {
+                min_north: 70_000.0,
+                max_north: 90_000.0,
+                min_east: 830_000.0,
+                max_east: 850_000.0,
+            }),
+            border.expand(10_000.0)
+        );
+    }
+}
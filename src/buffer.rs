@@ -0,0 +1,112 @@
+use crate::{Lv03, Wgs84};
+
+/// Fixed capacity collection of points which does not need an allocator
+#[derive(Clone, Debug)]
+pub struct PointBuffer<const N: usize> {
+    points: [Lv03; N],
+    len: usize,
+}
+
+impl<const N: usize> PointBuffer<N> {
+    pub fn new() -> Self {
+        PointBuffer {
+            points: core::array::from_fn(|_| Lv03 {
+                north: 0.0,
+                east: 0.0,
+                altitude: None,
+            }),
+            len: 0,
+        }
+    }
+
+    /// Appends a point. If the buffer is full the point is handed back as error
+    pub fn push(&mut self, p: Lv03) -> Result<(), Lv03> {
+        if self.len == N {
+            return Err(p);
+        }
+        self.points[self.len] = p;
+        self.len += 1;
+        Ok(())
+    }
+
+    pub fn as_slice(&self) -> &[Lv03] {
+        &self.points[..self.len]
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Converts all points and writes them to the beginning of `out`.
+    /// Panics if `out` is shorter than the number of points in the buffer
+    pub fn convert_all_to_wgs84(&self, out: &mut [Wgs84]) {
+        assert!(out.len() >= self.len, "output buffer too small");
+        for (p, wgs84) in self.as_slice().iter().zip(out.iter_mut()) {
+            *wgs84 = p.to_wgs84();
+        }
+    }
+}
+
+impl<const N: usize> Default for PointBuffer<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_and_convert() {
+        let mut buffer = PointBuffer::<2>::new();
+        assert!(buffer.is_empty());
+        let bundeshaus = Lv03::new(199_498.43, 600_421.43, 542.8).unwrap();
+        let matterhorn = Lv03::new(91_673.72, 617_049.89, 4477.4).unwrap();
+        assert_eq!(Ok(()), buffer.push(bundeshaus.clone()));
+        assert_eq!(Ok(()), buffer.push(matterhorn.clone()));
+        assert!(buffer.is_full());
+        assert_eq!(Err(bundeshaus.clone()), buffer.push(bundeshaus.clone()));
+        assert_eq!(&[bundeshaus.clone(), matterhorn.clone()], buffer.as_slice());
+
+        let mut out = [
+            Wgs84 {
+                longitude: 0.0,
+                latitude: 0.0,
+                altitude: None,
+            },
+            Wgs84 {
+                longitude: 0.0,
+                latitude: 0.0,
+                altitude: None,
+            },
+            Wgs84 {
+                longitude: 0.0,
+                latitude: 0.0,
+                altitude: None,
+            },
+        ];
+        buffer.convert_all_to_wgs84(&mut out);
+        assert_eq!(bundeshaus.to_wgs84(), out[0]);
+        assert_eq!(matterhorn.to_wgs84(), out[1]);
+        assert_eq!(None, out[2].altitude);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_convert_into_short_buffer() {
+        let mut buffer = PointBuffer::<1>::new();
+        buffer
+            .push(Lv03::new(199_498.43, 600_421.43, 542.8).unwrap())
+            .unwrap();
+        buffer.convert_all_to_wgs84(&mut []);
+    }
+}
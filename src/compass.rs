@@ -0,0 +1,119 @@
+use crate::Lv03;
+use core::fmt;
+
+/// The eight principal compass directions
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompassDirection {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl CompassDirection {
+    /// Direction closest to the bearing in degrees clockwise from north
+    pub fn from_bearing(bearing_deg: f64) -> Self {
+        const DIRECTIONS: [CompassDirection; 8] = [
+            CompassDirection::N,
+            CompassDirection::NE,
+            CompassDirection::E,
+            CompassDirection::SE,
+            CompassDirection::S,
+            CompassDirection::SW,
+            CompassDirection::W,
+            CompassDirection::NW,
+        ];
+        let bearing = crate::normalize_bearing(bearing_deg);
+        DIRECTIONS[((bearing + 22.5) / 45.0) as usize % 8]
+    }
+}
+
+impl fmt::Display for CompassDirection {
+    /// Formats as abbreviation, e.g. `NE`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let abbreviation = match self {
+            CompassDirection::N => "N",
+            CompassDirection::NE => "NE",
+            CompassDirection::E => "E",
+            CompassDirection::SE => "SE",
+            CompassDirection::S => "S",
+            CompassDirection::SW => "SW",
+            CompassDirection::W => "W",
+            CompassDirection::NW => "NW",
+        };
+        f.write_str(abbreviation)
+    }
+}
+
+impl Lv03 {
+    /// Compass direction of the other point on the grid, see `bearing_to`
+    pub fn direction_to(&self, other: &Lv03) -> CompassDirection {
+        CompassDirection::from_bearing(self.bearing_to(other))
+    }
+
+    /// Magnetic bearing in degrees (0..360) to the other point, e.g. for a compass heading.
+    /// The grid bearing is first turned into a true bearing using the meridian convergence at
+    /// this point, then the magnetic declination is subtracted. `declination_deg` is positive if
+    /// magnetic north lies east of true north, in Switzerland currently about 2 to 3 degrees
+    pub fn magnetic_bearing_to(&self, other: &Lv03, declination_deg: f64) -> f64 {
+        let true_bearing = self.bearing_to(other) - self.grid_north_to_true_north_angle();
+        crate::normalize_bearing(true_bearing - declination_deg)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_bearing() {
+        assert_eq!(CompassDirection::N, CompassDirection::from_bearing(0.0));
+        assert_eq!(CompassDirection::N, CompassDirection::from_bearing(22.4));
+        assert_eq!(CompassDirection::NE, CompassDirection::from_bearing(22.5));
+        assert_eq!(CompassDirection::E, CompassDirection::from_bearing(90.0));
+        assert_eq!(CompassDirection::S, CompassDirection::from_bearing(180.0));
+        assert_eq!(CompassDirection::NW, CompassDirection::from_bearing(337.4));
+        assert_eq!(CompassDirection::N, CompassDirection::from_bearing(359.0));
+        assert_eq!(CompassDirection::W, CompassDirection::from_bearing(-90.0));
+    }
+
+    #[test]
+    fn test_direction_to() {
+        let p = Lv03::new(200_000.0, 600_000.0, 500.0).unwrap();
+        let ne = Lv03::new(201_000.0, 601_000.0, 500.0).unwrap();
+        let s = Lv03::new(199_000.0, 600_100.0, 500.0).unwrap();
+        assert_eq!(CompassDirection::NE, p.direction_to(&ne));
+        assert_eq!(CompassDirection::SW, ne.direction_to(&p));
+        assert_eq!(CompassDirection::S, p.direction_to(&s));
+    }
+
+    #[test]
+    fn test_magnetic_bearing_to() {
+        // Along the meridian through Bern grid and true north coincide
+        let bern = Lv03::new(200_000.0, 600_000.0, 500.0).unwrap();
+        let north = Lv03::new(210_000.0, 600_000.0, 500.0).unwrap();
+        assert!((bern.magnetic_bearing_to(&north, -2.5) - 2.5).abs() < 1e-3);
+        assert!((bern.magnetic_bearing_to(&north, 2.5) - 357.5).abs() < 1e-3);
+
+        // In St. Gallen grid north lies about 1.4° east of true north
+        let st_gallen = Lv03::new(254_000.0, 746_000.0, 670.0).unwrap();
+        let east = Lv03::new(254_000.0, 756_000.0, 670.0).unwrap();
+        let bearing = st_gallen.magnetic_bearing_to(&east, 3.0);
+        assert!((bearing - (90.0 + 1.4 - 3.0)).abs() < 0.05);
+        let expected = 90.0 + st_gallen.meridian_convergence() - 3.0;
+        assert!((bearing - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_display() {
+        extern crate std;
+        use std::string::ToString;
+
+        assert_eq!("NE", CompassDirection::NE.to_string());
+        assert_eq!("W", CompassDirection::W.to_string());
+    }
+}
@@ -0,0 +1,262 @@
+//! Relation of points and segments to the valid domain of the swiss coordinate system
+
+use crate::{Bounds, Lv03, Wgs84};
+
+/// True if any point of the straight segment between `a` and `b` lies outside of
+/// `Bounds::SWITZERLAND`, i.e. would be rejected by `Lv03::new`
+pub fn segment_exits_domain(a: &Lv03, b: &Lv03) -> bool {
+    // The domain is a rectangle and therefore convex: a segment between two points inside of
+    // it never crosses any of the four edges
+    !Bounds::SWITZERLAND.contains(a.north, a.east) || !Bounds::SWITZERLAND.contains(b.north, b.east)
+}
+
+impl Wgs84 {
+    /// Like `to_lv03`, but positions outside of the swiss coordinate system are clamped to the
+    /// closest point within `Bounds::SWITZERLAND` instead of failing. The result may therefore
+    /// be far from the true projection of the position, use it only where showing the
+    /// closest point within the country is acceptable. A NaN latitude or longitude gives NaN
+    /// coordinates, which lie outside of the bounds
+    pub fn to_lv03_or_nearest(&self) -> Lv03 {
+        self.project().clamp_to_bounds(&Bounds::SWITZERLAND)
+    }
+}
+
+impl Lv03 {
+    /// Closest point within the bounds, i.e. each coordinate is clamped to its valid range.
+    /// NaN coordinates stay NaN. Panics like `f64::clamp` if a range of the bounds is empty,
+    /// i.e. its start is greater than its end, or if one of its ends is NaN
+    pub fn clamp_to_bounds(&self, bounds: &Bounds) -> Lv03 {
+        Lv03 {
+            north: self.north.clamp(*bounds.north.start(), *bounds.north.end()),
+            east: self.east.clamp(*bounds.east.start(), *bounds.east.end()),
+            altitude: self.altitude,
+        }
+    }
+
+    /// Distance in meters to the closest of the four edges of `Bounds::SWITZERLAND`, where the
+    /// approximation formulas are least accurate. Zero on an edge, negative outside of the bounds
+    pub fn distance_to_boundary(&self) -> f64 {
+        let bounds = Bounds::SWITZERLAND;
+        let north = (self.north - *bounds.north.start()).min(*bounds.north.end() - self.north);
+        let east = (self.east - *bounds.east.start()).min(*bounds.east.end() - self.east);
+        north.min(east)
+    }
+
+    /// Point where the straight line from `inside` towards the `(north, east, altitude)`
+    /// position `outside` leaves `Bounds::SWITZERLAND`. Unlike clamping each axis this keeps
+    /// the direction from `inside`. The altitude is interpolated along the line, it is none
+    /// if `inside` has no altitude. If `outside` lies within the bounds it is returned as is.
+    /// `inside` must lie within the bounds
+    pub fn snap_toward(outside: (f64, f64, f64), inside: &Lv03) -> Lv03 {
+        let (north, east, altitude) = outside;
+        let target = Lv03 {
+            north,
+            east,
+            altitude: inside.altitude.map(|_| altitude),
+        };
+        let t = exit_parameter(inside, &target, &Bounds::SWITZERLAND);
+        inside.lerp(&target, t)
+    }
+
+    /// Fraction `t` within 0 to 1 of the way from the point towards the `(north, east,
+    /// altitude)` position `toward` at which the straight line leaves `Bounds::SWITZERLAND`,
+    /// e.g. to stop an interpolation at the border. The point itself must lie within the
+    /// bounds, which is the case for points created by `new`. Returns none if it does not, or if
+    /// `toward` lies within the bounds and the line therefore never leaves them
+    pub fn boundary_crossing_t(&self, toward: (f64, f64, f64)) -> Option<f64> {
+        let (north, east, altitude) = toward;
+        if !Bounds::SWITZERLAND.contains(self.north, self.east)
+            || Bounds::SWITZERLAND.contains(north, east)
+        {
+            return None;
+        }
+        let target = Lv03 {
+            north,
+            east,
+            altitude: Some(altitude),
+        };
+        let t = exit_parameter(self, &target, &Bounds::SWITZERLAND);
+        Some(t.clamp(0.0, 1.0))
+    }
+}
+
+/// Largest fraction `t` within 0 to 1 of the way from `from` to `to` which still lies within
+/// the bounds, 1 if `to` is within the bounds. `from` must lie within the bounds
+fn exit_parameter(from: &Lv03, to: &Lv03, bounds: &Bounds) -> f64 {
+    let axis = |from: f64, to: f64, min: f64, max: f64| {
+        if to > max {
+            (max - from) / (to - from)
+        } else if to < min {
+            (min - from) / (to - from)
+        } else {
+            1.0
+        }
+    };
+    let t_north = axis(
+        from.north,
+        to.north,
+        *bounds.north.start(),
+        *bounds.north.end(),
+    );
+    let t_east = axis(from.east, to.east, *bounds.east.start(), *bounds.east.end());
+    t_north.min(t_east)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_boundary_crossing_t() {
+        let p = Lv03::new(200_000.0, 840_000.0, 500.0).unwrap();
+        let t = p
+            .boundary_crossing_t((200_000.0, 860_000.0, 700.0))
+            .unwrap();
+        assert_eq!(0.5, t);
+        let target = Lv03 {
+            north: 200_000.0,
+            east: 860_000.0,
+            altitude: Some(700.0),
+        };
+        let border = p.lerp(&target, t);
+        assert_eq!(850_000.0, border.east);
+        assert_eq!(Some(600.0), border.altitude);
+
+        // Past the north eastern corner the eastern edge is crossed first
+        let t = p.boundary_crossing_t((310_000.0, 860_000.0, 0.0)).unwrap();
+        assert_eq!(0.5, t);
+
+        assert_eq!(None, p.boundary_crossing_t((250_000.0, 700_000.0, 0.0)));
+        assert_eq!(None, p.boundary_crossing_t((200_000.0, 850_000.0, 0.0)));
+
+        // The start point must lie within the bounds
+        let outside = Lv03 {
+            north: 310_000.0,
+            east: 700_000.0,
+            altitude: None,
+        };
+        assert_eq!(
+            None,
+            outside.boundary_crossing_t((320_000.0, 700_000.0, 0.0))
+        );
+        assert_eq!(
+            None,
+            outside.boundary_crossing_t((200_000.0, 700_000.0, 0.0))
+        );
+        // Starting on the edge and heading out leaves immediately
+        let edge = Lv03::new_2d(300_000.0, 700_000.0).unwrap();
+        assert_eq!(
+            Some(0.0),
+            edge.boundary_crossing_t((320_000.0, 700_000.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn test_distance_to_boundary() {
+        let bern = Lv03::new(199_498.43, 600_421.43, 542.8).unwrap();
+        assert!((bern.distance_to_boundary() - 100_501.57).abs() < 1e-6);
+
+        let basel = Lv03::new_2d(268_000.0, 611_000.0).unwrap();
+        assert_eq!(32_000.0, basel.distance_to_boundary());
+        let west = Lv03::new_2d(200_000.0, 482_500.0).unwrap();
+        assert_eq!(2_500.0, west.distance_to_boundary());
+        let corner = Lv03::new_2d(70_000.0, 480_000.0).unwrap();
+        assert_eq!(0.0, corner.distance_to_boundary());
+    }
+
+    #[test]
+    fn test_segment_exits_domain() {
+        let inside = Lv03::new(200_000.0, 840_000.0, 500.0).unwrap();
+        let east_edge = Lv03::new(210_000.0, 850_000.0, 500.0).unwrap();
+        let outside = Lv03 {
+            north: 210_000.0,
+            east: 860_000.0,
+            altitude: None,
+        };
+        assert!(!segment_exits_domain(&inside, &east_edge));
+        assert!(segment_exits_domain(&inside, &outside));
+        assert!(segment_exits_domain(&outside, &inside));
+        assert!(segment_exits_domain(&outside, &outside));
+        assert!(!segment_exits_domain(&inside, &inside));
+    }
+
+    #[test]
+    fn test_snap_toward() {
+        let inside = Lv03::new(200_000.0, 840_000.0, 500.0).unwrap();
+        let snapped = Lv03::snap_toward((210_000.0, 860_000.0, 700.0), &inside);
+        assert_eq!(Lv03::new(205_000.0, 850_000.0, 600.0), Some(snapped));
+
+        // Leaves through the northern edge first
+        let inside = Lv03::new_2d(290_000.0, 840_000.0).unwrap();
+        let snapped = Lv03::snap_toward((310_000.0, 860_000.0, 700.0), &inside);
+        assert_eq!(Lv03::new_2d(300_000.0, 850_000.0), Some(snapped));
+        let snapped = Lv03::snap_toward((320_000.0, 860_000.0, 700.0), &inside);
+        assert_eq!(300_000.0, snapped.north);
+        assert!((snapped.east - 846_666.666_666).abs() < 1e-3);
+
+        let target = Lv03::snap_toward((295_000.0, 845_000.0, 700.0), &inside);
+        assert_eq!(Lv03::new_2d(295_000.0, 845_000.0), Some(target));
+    }
+
+    #[test]
+    fn test_clamp_to_bounds() {
+        let outside = Lv03 {
+            north: 310_000.0,
+            east: 700_000.0,
+            altitude: Some(400.0),
+        };
+        assert_eq!(
+            Lv03::new(300_000.0, 700_000.0, 400.0),
+            Some(outside.clamp_to_bounds(&Bounds::SWITZERLAND))
+        );
+        assert_eq!(
+            Lv03::new_within(238_000.0, 754_000.0, 400.0, &Bounds::LIECHTENSTEIN),
+            Some(outside.clamp_to_bounds(&Bounds::LIECHTENSTEIN))
+        );
+        let inside = Lv03::new(199_498.43, 600_421.43, 542.8).unwrap();
+        assert_eq!(inside, inside.clamp_to_bounds(&Bounds::SWITZERLAND));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_clamp_to_inverted_bounds() {
+        let inverted = Bounds {
+            north: 300_000.0..=70_000.0,
+            east: 480_000.0..=850_000.0,
+        };
+        let inside = Lv03::new(199_498.43, 600_421.43, 542.8).unwrap();
+        inside.clamp_to_bounds(&inverted);
+    }
+
+    #[test]
+    fn test_to_lv03_or_nearest() {
+        let bundeshaus = Wgs84 {
+            longitude: 7.44417,
+            latitude: 46.94658,
+            altitude: Some(591.8),
+        };
+        assert_eq!(
+            bundeshaus.to_lv03().unwrap(),
+            bundeshaus.to_lv03_or_nearest()
+        );
+
+        // Milano lies south of the swiss coordinate system
+        let milano = Wgs84 {
+            longitude: 9.19,
+            latitude: 45.46,
+            altitude: None,
+        };
+        assert_eq!(None, milano.to_lv03());
+        let nearest = milano.to_lv03_or_nearest();
+        assert_eq!(70_000.0, nearest.north);
+        assert_eq!(milano.to_lv03_flagged().0.unwrap().east, nearest.east);
+
+        let invalid = Wgs84 {
+            longitude: f64::NAN,
+            latitude: 46.94658,
+            altitude: None,
+        };
+        let nearest = invalid.to_lv03_or_nearest();
+        assert!(nearest.north.is_nan() && nearest.east.is_nan());
+    }
+}
@@ -0,0 +1,55 @@
+//! Interoperability with the `geo-types` crate, so the algorithms of the `geo` ecosystem can be
+//! applied to tracks on the LV03 grid
+
+use crate::{Bounds, Lv03};
+use alloc::vec::Vec;
+use geo_types::{Coord, LineString};
+
+impl Lv03 {
+    /// Line string through the points with `x` as east and `y` as north coordinate, matching
+    /// the axis order of most GIS software. Altitudes are dropped
+    pub fn to_line_string(points: &[Lv03]) -> LineString<f64> {
+        points
+            .iter()
+            .map(|p| Coord {
+                x: p.east,
+                y: p.north,
+            })
+            .collect()
+    }
+
+    /// Points of a line string with `x` as east and `y` as north coordinate, without
+    /// altitudes. Returns none if any of the points lies outside of the swiss coordinate system
+    pub fn from_line_string(line: &LineString<f64>) -> Option<Vec<Lv03>> {
+        line.coords()
+            .map(|c| Lv03::from_parts(c.y, c.x, None, &Bounds::SWITZERLAND))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_string_roundtrip() {
+        let track = [
+            Lv03::new_2d(199_498.43, 600_421.43).unwrap(),
+            Lv03::new_2d(200_100.0, 601_000.0).unwrap(),
+            Lv03::new_2d(201_000.0, 600_500.0).unwrap(),
+        ];
+        let line = Lv03::to_line_string(&track);
+        assert_eq!(3, line.0.len());
+        assert_eq!(600_421.43, line.0[0].x);
+        assert_eq!(199_498.43, line.0[0].y);
+        assert_eq!(Some(&track[..]), Lv03::from_line_string(&line).as_deref());
+    }
+
+    #[test]
+    fn test_from_line_string_invalid() {
+        let line: LineString<f64> = [(600_000.0, 200_000.0), (2_600_000.0, 1_200_000.0)]
+            .into_iter()
+            .collect();
+        assert_eq!(None, Lv03::from_line_string(&line));
+    }
+}
@@ -0,0 +1,800 @@
+//! Planar geometry on the LV03 grid, which is conformal and metric
+
+use crate::{Lv03, Wgs84};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Twice the signed area of the triangle, positive if the points are counter-clockwise
+fn cross(a: &Lv03, b: &Lv03, c: &Lv03) -> f64 {
+    (b.east - a.east) * (c.north - a.north) - (b.north - a.north) * (c.east - a.east)
+}
+
+/// Area of the triangle in square meters, ignoring the altitude
+pub fn triangle_area(a: &Lv03, b: &Lv03, c: &Lv03) -> f64 {
+    cross(a, b, c).abs() / 2.0
+}
+
+/// True if `b` lies within `tolerance_m` of the line through `a` and `c`, ignoring the altitude.
+/// If `a` and `c` coincide, the distance between `a` and `b` is compared instead
+pub fn are_collinear(a: &Lv03, b: &Lv03, c: &Lv03, tolerance_m: f64) -> bool {
+    let tolerance_squared = tolerance_m * tolerance_m;
+    let length_squared =
+        (c.east - a.east) * (c.east - a.east) + (c.north - a.north) * (c.north - a.north);
+    if length_squared == 0.0 {
+        let distance_squared =
+            (b.east - a.east) * (b.east - a.east) + (b.north - a.north) * (b.north - a.north);
+        return distance_squared <= tolerance_squared;
+    }
+    // The perpendicular distance is the cross product divided by the length of a-c,
+    // compare the squares to avoid the square root
+    let cross = cross(a, c, b);
+    cross * cross <= tolerance_squared * length_squared
+}
+
+/// Area of the polygon in square meters, ignoring the altitude. The polygon is closed
+/// implicitly and may be given in either orientation, but must not intersect itself.
+/// Returns 0 for fewer than three points
+pub fn polygon_area(points: &[Lv03]) -> f64 {
+    let mut accumulator = AreaAccumulator::new();
+    for p in points {
+        accumulator.push(p);
+    }
+    accumulator.finish()
+}
+
+/// Centroid (center of mass) of the area of the polygon, ignoring the altitude. Unlike the mean
+/// of the vertices it does not depend on how densely the outline is sampled. The polygon is
+/// closed implicitly and must not intersect itself. Returns none if the area is zero.
+/// The centroid has no altitude and may lie outside of a concave polygon
+pub fn polygon_centroid(points: &[Lv03]) -> Option<Lv03> {
+    let (first, rest) = points.split_first()?;
+    // Relative to the first vertex, so the closing edge does not contribute
+    let mut previous = (0.0, 0.0);
+    let mut area_sum = 0.0;
+    let mut east_sum = 0.0;
+    let mut north_sum = 0.0;
+    for p in rest {
+        let current = (p.east - first.east, p.north - first.north);
+        let cross = previous.0 * current.1 - current.0 * previous.1;
+        area_sum += cross;
+        east_sum += (previous.0 + current.0) * cross;
+        north_sum += (previous.1 + current.1) * cross;
+        previous = current;
+    }
+    if area_sum == 0.0 {
+        return None;
+    }
+    Some(Lv03 {
+        north: first.north + north_sum / (3.0 * area_sum),
+        east: first.east + east_sum / (3.0 * area_sum),
+        altitude: None,
+    })
+}
+
+/// WGS84 position of the `polygon_centroid`, e.g. to place a label on a web map. The centroid
+/// is computed on the metric grid, which is more accurate than averaging latitudes and
+/// longitudes. Returns none if the area of the polygon is zero
+pub fn polygon_centroid_wgs84(points: &[Lv03]) -> Option<Wgs84> {
+    polygon_centroid(points).map(|centroid| centroid.to_wgs84())
+}
+
+/// Computes the area of a polygon vertex by vertex without storing the ring,
+/// the streaming counterpart of `polygon_area`
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AreaAccumulator {
+    /// First vertex, the coordinates are taken relative to it to keep the sums small
+    first: Option<(f64, f64)>,
+    /// Previous vertex relative to the first one
+    previous: (f64, f64),
+    /// Twice the signed area so far
+    sum: f64,
+}
+
+impl AreaAccumulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the next vertex of the polygon
+    pub fn push(&mut self, p: &Lv03) {
+        let (first_east, first_north) = match self.first {
+            Some(first) => first,
+            None => {
+                self.first = Some((p.east, p.north));
+                return;
+            }
+        };
+        let current = (p.east - first_east, p.north - first_north);
+        self.sum += self.previous.0 * current.1 - current.0 * self.previous.1;
+        self.previous = current;
+    }
+
+    /// Area of the polygon in square meters. The closing edge back to the first vertex
+    /// does not contribute, since the coordinates are relative to the first vertex
+    pub fn finish(self) -> f64 {
+        self.sum.abs() / 2.0
+    }
+}
+
+/// Area of the triangle in square meters including the altitude, i.e. the true surface of a
+/// terrain facet. If any of the points has no altitude the planar `triangle_area` is returned
+#[cfg(feature = "libm")]
+pub fn triangle_area_3d(a: &Lv03, b: &Lv03, c: &Lv03) -> f64 {
+    let (a_altitude, b_altitude, c_altitude) = match (a.altitude, b.altitude, c.altitude) {
+        (Some(a), Some(b), Some(c)) => (a, b, c),
+        _ => return triangle_area(a, b, c),
+    };
+    let u = (b.east - a.east, b.north - a.north, b_altitude - a_altitude);
+    let v = (c.east - a.east, c.north - a.north, c_altitude - a_altitude);
+    let normal = (
+        u.1 * v.2 - u.2 * v.1,
+        u.2 * v.0 - u.0 * v.2,
+        u.0 * v.1 - u.1 * v.0,
+    );
+    libm::sqrt(normal.0 * normal.0 + normal.1 * normal.1 + normal.2 * normal.2) / 2.0
+}
+
+/// Perpendicular bisector of the two points as the midpoint and the bearing of the bisector
+/// in degrees clockwise from grid north, 90 degrees to the right of the bearing from `a` to
+/// `b`. Returns none if the points coincide horizontally, since the bisector is undefined then
+#[cfg(feature = "libm")]
+pub fn perpendicular_bisector(a: &Lv03, b: &Lv03) -> Option<(Lv03, f64)> {
+    if a.north == b.north && a.east == b.east {
+        return None;
+    }
+    let bearing = (a.bearing_to(b) + 90.0) % 360.0;
+    Some((a.midpoint(b), bearing))
+}
+
+/// Intersection point of the segments `a1`-`a2` and `b1`-`b2`, none if they do not cross.
+/// Parallel and collinear segments return none, even if they overlap.
+/// The altitude is interpolated along the segment `a1`-`a2`
+pub fn segment_intersection(a1: &Lv03, a2: &Lv03, b1: &Lv03, b2: &Lv03) -> Option<Lv03> {
+    let r = (a2.east - a1.east, a2.north - a1.north);
+    let s = (b2.east - b1.east, b2.north - b1.north);
+    let (t, u) = line_intersection(a1, r, b1, s)?;
+    if (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u) {
+        Some(a1.lerp(a2, t))
+    } else {
+        None
+    }
+}
+
+/// Parameters `(t, u)` of the intersection `a + t * r = b + u * s` of two lines given by a point
+/// and an `(east, north)` direction, none if the lines are parallel
+fn line_intersection(a: &Lv03, r: (f64, f64), b: &Lv03, s: (f64, f64)) -> Option<(f64, f64)> {
+    let denominator = r.0 * s.1 - r.1 * s.0;
+    if denominator == 0.0 {
+        return None;
+    }
+    let d = (b.east - a.east, b.north - a.north);
+    let t = (d.0 * s.1 - d.1 * s.0) / denominator;
+    let u = (d.0 * r.1 - d.1 * r.0) / denominator;
+    Some((t, u))
+}
+
+/// Position fix from grid bearings (degrees clockwise from grid north) of two sightlines through
+/// known points, e.g. a resection from bearings taken towards two landmarks. Each sightline is
+/// treated as an infinite line, so a bearing may be taken from or towards its point.
+/// Returns none if the sightlines are parallel. The fix has no altitude and is not checked
+/// against the bounds of the swiss coordinate system
+#[cfg(feature = "libm")]
+pub fn triangulate(p1: &Lv03, bearing1_deg: f64, p2: &Lv03, bearing2_deg: f64) -> Option<Lv03> {
+    // Rounding keeps the directions of parallel bearings from being exactly parallel
+    if libm::sin((bearing2_deg - bearing1_deg).to_radians()).abs() < 1e-9 {
+        return None;
+    }
+    let (sin_1, cos_1) = libm::sincos(bearing1_deg.to_radians());
+    let (sin_2, cos_2) = libm::sincos(bearing2_deg.to_radians());
+    let (t, _) = line_intersection(p1, (sin_1, cos_1), p2, (sin_2, cos_2))?;
+    Some(Lv03 {
+        north: p1.north + t * cos_1,
+        east: p1.east + t * sin_1,
+        altitude: None,
+    })
+}
+
+#[cfg(feature = "libm")]
+impl Lv03 {
+    /// Offsets `(along_track_m, cross_track_m)` of the point relative to the line from `a` to `b`,
+    /// ignoring the altitude. The along track distance is measured from `a` in the direction of
+    /// `b` and is negative before `a` and greater than the segment length beyond `b`.
+    /// The cross track distance is positive if the point is to the left of the direction of
+    /// travel and negative to the right. If `a` and `b` coincide the along track distance is 0
+    /// and the cross track distance is the distance to `a`
+    pub fn track_offsets(&self, a: &Lv03, b: &Lv03) -> (f64, f64) {
+        let length = a.distance_2d(b);
+        if length == 0.0 {
+            return (0.0, a.distance_2d(self));
+        }
+        let along = ((b.east - a.east) * (self.east - a.east)
+            + (b.north - a.north) * (self.north - a.north))
+            / length;
+        (along, cross(a, b, self) / length)
+    }
+
+    /// Foot of the perpendicular from the point onto the infinite line through `origin` in the
+    /// direction of the grid bearing (degrees clockwise from grid north), e.g. to snap to a road
+    /// given by a point and its orientation. The altitude of the point is kept. The result is
+    /// not checked against the bounds of the swiss coordinate system
+    pub fn project_onto_line(&self, origin: &Lv03, bearing_deg: f64) -> Lv03 {
+        let (sin, cos) = libm::sincos(bearing_deg.to_radians());
+        let along = (self.east - origin.east) * sin + (self.north - origin.north) * cos;
+        Lv03 {
+            north: origin.north + along * cos,
+            east: origin.east + along * sin,
+            altitude: self.altitude,
+        }
+    }
+}
+
+/// Smallest circle containing all points as `(center, radius_m)`, ignoring the altitude.
+/// The center has no altitude and is not checked against the bounds of the swiss coordinate
+/// system. Returns none for an empty slice.
+///
+/// The points are visited in a scrambled order, so that ordered input like a track along an arc
+/// takes close to linear time. The worst case remains cubic in the number of points
+#[cfg(feature = "libm")]
+pub fn minimum_enclosing_circle(points: &[Lv03]) -> Option<(Lv03, f64)> {
+    let (first, _) = points.split_first()?;
+    // Iterative form of Welzl's algorithm, with coordinates relative to the first point
+    let order = ScrambledOrder::new(points.len());
+    let point = |k: usize| {
+        let p = &points[order.index(k)];
+        (p.east - first.east, p.north - first.north)
+    };
+    let mut circle = Circle::from_one(point(0));
+    for i in 0..points.len() {
+        let p = point(i);
+        if circle.contains(p) {
+            continue;
+        }
+        circle = Circle::from_one(p);
+        for j in 0..i {
+            let q = point(j);
+            if circle.contains(q) {
+                continue;
+            }
+            circle = Circle::from_two(p, q);
+            for k in 0..j {
+                let r = point(k);
+                if !circle.contains(r) {
+                    circle = Circle::from_three(p, q, r);
+                }
+            }
+        }
+    }
+    let center = Lv03 {
+        north: first.north + circle.center.1,
+        east: first.east + circle.center.0,
+        altitude: None,
+    };
+    Some((center, libm::sqrt(circle.radius_squared)))
+}
+
+/// Deterministic permutation of `0..len` which visits the indices with a stride of about the
+/// golden ratio times the length. Neighboring input indices end up far apart, which replaces the
+/// random shuffle of Welzl's algorithm without allocating
+#[cfg(feature = "libm")]
+struct ScrambledOrder {
+    len: usize,
+    stride: usize,
+}
+
+#[cfg(feature = "libm")]
+impl ScrambledOrder {
+    fn new(len: usize) -> Self {
+        // The stride must be coprime with the length to visit every index exactly once
+        let mut stride = (len as f64 * 0.618_033_988_749_895) as usize + 1;
+        while gcd(stride, len) != 1 {
+            stride += 1;
+        }
+        ScrambledOrder { len, stride }
+    }
+
+    fn index(&self, k: usize) -> usize {
+        ((k as u128 * self.stride as u128) % self.len as u128) as usize
+    }
+}
+
+#[cfg(feature = "libm")]
+fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Circle in plane coordinates `(east, north)`
+#[cfg(feature = "libm")]
+struct Circle {
+    center: (f64, f64),
+    radius_squared: f64,
+}
+
+#[cfg(feature = "libm")]
+impl Circle {
+    fn from_one(p: (f64, f64)) -> Self {
+        Circle {
+            center: p,
+            radius_squared: 0.0,
+        }
+    }
+
+    /// Circle with the two points on its diameter
+    fn from_two(p: (f64, f64), q: (f64, f64)) -> Self {
+        let center = ((p.0 + q.0) / 2.0, (p.1 + q.1) / 2.0);
+        Circle {
+            center,
+            radius_squared: Self::distance_squared(center, p),
+        }
+    }
+
+    /// Circumcircle of the triangle, or the largest circle through two of the points
+    /// if the points are (nearly) collinear
+    fn from_three(p: (f64, f64), q: (f64, f64), r: (f64, f64)) -> Self {
+        let b = (q.0 - p.0, q.1 - p.1);
+        let c = (r.0 - p.0, r.1 - p.1);
+        let b_squared = b.0 * b.0 + b.1 * b.1;
+        let c_squared = c.0 * c.0 + c.1 * c.1;
+        let d = 2.0 * (b.0 * c.1 - b.1 * c.0);
+        // Relative to the lengths of the sides, nearly collinear points would otherwise give a
+        // huge circle determined by rounding errors
+        if d.abs() <= 1e-9 * libm::sqrt(b_squared * c_squared) {
+            return [
+                Self::from_two(p, q),
+                Self::from_two(p, r),
+                Self::from_two(q, r),
+            ]
+            .into_iter()
+            .max_by(|a, b| a.radius_squared.total_cmp(&b.radius_squared))
+            .unwrap();
+        }
+        let offset = (
+            (c.1 * b_squared - b.1 * c_squared) / d,
+            (b.0 * c_squared - c.0 * b_squared) / d,
+        );
+        Circle {
+            center: (p.0 + offset.0, p.1 + offset.1),
+            radius_squared: offset.0 * offset.0 + offset.1 * offset.1,
+        }
+    }
+
+    /// Contains check with a small tolerance for rounding errors
+    fn contains(&self, p: (f64, f64)) -> bool {
+        Self::distance_squared(self.center, p) <= self.radius_squared * (1.0 + 1e-12) + 1e-9
+    }
+
+    fn distance_squared(a: (f64, f64), b: (f64, f64)) -> f64 {
+        (a.0 - b.0) * (a.0 - b.0) + (a.1 - b.1) * (a.1 - b.1)
+    }
+}
+
+/// Convex hull of the points in counter-clockwise order, starting with the westernmost point.
+/// Points on the edges of the hull are omitted. Collinear input gives the two endpoints,
+/// less than three distinct points are returned as they are
+#[cfg(feature = "alloc")]
+pub fn convex_hull(points: &[Lv03]) -> Vec<Lv03> {
+    let mut sorted: Vec<&Lv03> = points.iter().collect();
+    sorted.sort_by(|a, b| a.east.total_cmp(&b.east).then(a.north.total_cmp(&b.north)));
+    sorted.dedup_by(|a, b| a.east == b.east && a.north == b.north);
+    if sorted.len() < 3 {
+        return sorted.into_iter().cloned().collect();
+    }
+
+    // Andrew's monotone chain: lower hull from west to east, then upper hull back
+    let mut hull: Vec<&Lv03> = Vec::with_capacity(2 * sorted.len());
+    for &p in sorted.iter() {
+        while hull.len() >= 2 && cross(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0.0 {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+    let lower_len = hull.len() + 1;
+    for &p in sorted.iter().rev().skip(1) {
+        while hull.len() >= lower_len && cross(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0.0
+        {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+    // The last point is the westernmost point again
+    hull.pop();
+    hull.into_iter().cloned().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_triangle_area() {
+        let a = Lv03::new(200_000.0, 600_000.0, 500.0).unwrap();
+        let b = Lv03::new(200_000.0, 600_300.0, 900.0).unwrap();
+        let c = Lv03::new(200_400.0, 600_000.0, 100.0).unwrap();
+        assert_eq!(60_000.0, triangle_area(&a, &b, &c));
+        assert_eq!(60_000.0, triangle_area(&c, &b, &a));
+        assert_eq!(0.0, triangle_area(&a, &b, &a.lerp(&b, 0.5)));
+    }
+
+    #[test]
+    fn test_are_collinear() {
+        let a = Lv03::new(200_000.0, 600_000.0, 500.0).unwrap();
+        let c = Lv03::new(200_000.0, 601_000.0, 900.0).unwrap();
+        // 3 m off the line
+        let b = Lv03::new_2d(200_003.0, 600_400.0).unwrap();
+        assert!(are_collinear(&a, &b, &c, 5.0));
+        assert!(are_collinear(&a, &b, &c, 3.0));
+        assert!(!are_collinear(&a, &b, &c, 2.9));
+        assert!(!are_collinear(&a, &b, &c, 0.0));
+        assert!(are_collinear(&a, &a.lerp(&c, 0.5), &c, 0.0));
+        // Beyond the end of the segment, but still on the line
+        assert!(are_collinear(&a, &b, &a.lerp(&c, 0.2), 3.0));
+
+        // Degenerate line
+        assert!(are_collinear(&a, &b, &a, 401.0));
+        assert!(!are_collinear(&a, &b, &a, 400.0));
+    }
+
+    #[test]
+    fn test_polygon_area() {
+        let square = [
+            Lv03::new(200_000.0, 600_000.0, 500.0).unwrap(),
+            Lv03::new(200_000.0, 601_000.0, 500.0).unwrap(),
+            Lv03::new(201_000.0, 601_000.0, 500.0).unwrap(),
+            Lv03::new(201_000.0, 600_000.0, 500.0).unwrap(),
+        ];
+        assert_eq!(1_000_000.0, polygon_area(&square));
+        let mut reversed = square.clone();
+        reversed.reverse();
+        assert_eq!(1_000_000.0, polygon_area(&reversed));
+
+        // Concave: a square with a triangular notch
+        let notched = [
+            square[0].clone(),
+            square[1].clone(),
+            square[2].clone(),
+            Lv03::new_2d(200_500.0, 600_500.0).unwrap(),
+            square[3].clone(),
+        ];
+        assert_eq!(750_000.0, polygon_area(&notched));
+
+        assert_eq!(
+            triangle_area(&square[0], &square[1], &square[2]),
+            polygon_area(&square[..3])
+        );
+        assert_eq!(0.0, polygon_area(&square[..2]));
+        assert_eq!(0.0, polygon_area(&[]));
+    }
+
+    #[test]
+    fn test_polygon_centroid() {
+        // L-shape made of a 2 km x 1 km and a 1 km x 1 km rectangle
+        let l_shape = [
+            Lv03::new_2d(200_000.0, 600_000.0).unwrap(),
+            Lv03::new_2d(200_000.0, 602_000.0).unwrap(),
+            Lv03::new_2d(201_000.0, 602_000.0).unwrap(),
+            Lv03::new_2d(201_000.0, 601_000.0).unwrap(),
+            Lv03::new_2d(202_000.0, 601_000.0).unwrap(),
+            Lv03::new_2d(202_000.0, 600_000.0).unwrap(),
+        ];
+        let centroid = polygon_centroid(&l_shape).unwrap();
+        // Weighted mean of the centers of the two rectangles
+        let expected_east = (2.0 * 601_000.0 + 600_500.0) / 3.0;
+        let expected_north = (2.0 * 200_500.0 + 201_500.0) / 3.0;
+        assert!((centroid.east - expected_east).abs() < 1e-6);
+        assert!((centroid.north - expected_north).abs() < 1e-6);
+        assert_eq!(None, centroid.altitude);
+
+        // The mean of the vertices differs
+        let summary = crate::summarize(&l_shape).unwrap();
+        assert!((summary.centroid.east - centroid.east).abs() > 100.0);
+
+        // Orientation does not matter
+        let mut reversed = l_shape.clone();
+        reversed.reverse();
+        let centroid_reversed = polygon_centroid(&reversed).unwrap();
+        assert!(centroid_reversed.distance_squared(&centroid) < 1e-12);
+
+        assert_eq!(None, polygon_centroid(&l_shape[..2]));
+        assert_eq!(None, polygon_centroid(&[]));
+    }
+
+    #[test]
+    fn test_polygon_centroid_wgs84() {
+        let triangle = [
+            Lv03::new_2d(199_000.0, 600_000.0).unwrap(),
+            Lv03::new_2d(199_000.0, 603_000.0).unwrap(),
+            Lv03::new_2d(202_000.0, 600_000.0).unwrap(),
+        ];
+        let expected = polygon_centroid(&triangle).unwrap().to_wgs84();
+        assert_eq!(Some(expected), polygon_centroid_wgs84(&triangle));
+        assert_eq!(None, polygon_centroid_wgs84(&triangle[..2]));
+    }
+
+    #[test]
+    fn test_area_accumulator() {
+        let square = [
+            Lv03::new(200_000.0, 600_000.0, 500.0).unwrap(),
+            Lv03::new(200_000.0, 601_000.0, 500.0).unwrap(),
+            Lv03::new(201_000.0, 601_000.0, 500.0).unwrap(),
+            Lv03::new(201_000.0, 600_000.0, 500.0).unwrap(),
+        ];
+        let mut accumulator = AreaAccumulator::new();
+        for p in &square {
+            accumulator.push(p);
+        }
+        assert_eq!(polygon_area(&square), accumulator.finish());
+        assert_eq!(0.0, AreaAccumulator::new().finish());
+    }
+
+    #[test]
+    #[cfg(feature = "libm")]
+    fn test_triangle_area_3d() {
+        // Vertical triangle, its planar area is zero
+        let a = Lv03::new(200_000.0, 600_000.0, 500.0).unwrap();
+        let b = Lv03::new(200_000.0, 600_300.0, 500.0).unwrap();
+        let c = Lv03::new(200_000.0, 600_000.0, 900.0).unwrap();
+        assert_eq!(0.0, triangle_area(&a, &b, &c));
+        assert_eq!(60_000.0, triangle_area_3d(&a, &b, &c));
+
+        // Flat triangle, same as the planar area
+        let c = Lv03::new(200_400.0, 600_000.0, 500.0).unwrap();
+        assert_eq!(60_000.0, triangle_area_3d(&a, &b, &c));
+
+        // Inclined by 45 degrees
+        let c = Lv03::new(200_400.0, 600_000.0, 900.0).unwrap();
+        assert!((triangle_area_3d(&a, &b, &c) - 60_000.0 * 2f64.sqrt()).abs() < 1e-6);
+
+        let c = Lv03::new_2d(200_400.0, 600_000.0).unwrap();
+        assert_eq!(60_000.0, triangle_area_3d(&a, &b, &c));
+    }
+
+    #[test]
+    #[cfg(feature = "libm")]
+    fn test_triangulate() {
+        let church = Lv03::new(201_000.0, 600_000.0, 600.0).unwrap();
+        let tower = Lv03::new(200_000.0, 602_000.0, 800.0).unwrap();
+        // Standing at N 200000 / E 600000 the church is due north and the tower due east
+        let fix = triangulate(&church, 0.0, &tower, 90.0).unwrap();
+        assert!((fix.north - 200_000.0).abs() < 1e-9);
+        assert!((fix.east - 600_000.0).abs() < 1e-9);
+        assert_eq!(None, fix.altitude);
+        // Back bearings give the same fix
+        let back = triangulate(&church, 180.0, &tower, 270.0).unwrap();
+        assert!(back.distance_squared(&fix) < 1e-12);
+
+        let fix = triangulate(&church, 135.0, &tower, 0.0).unwrap();
+        assert!((fix.north - 199_000.0).abs() < 1e-6);
+        assert!((fix.east - 602_000.0).abs() < 1e-6);
+
+        assert_eq!(None, triangulate(&church, 30.0, &tower, 30.0));
+        assert_eq!(None, triangulate(&church, 0.0, &tower, 180.0));
+    }
+
+    #[test]
+    #[cfg(feature = "libm")]
+    fn test_project_onto_line() {
+        let origin = Lv03::new(200_000.0, 600_000.0, 500.0).unwrap();
+        let p = Lv03::new(201_234.0, 600_321.0, 700.0).unwrap();
+
+        let north = p.project_onto_line(&origin, 0.0);
+        assert!((north.east - origin.east).abs() < 1e-9);
+        assert!((north.north - 201_234.0).abs() < 1e-9);
+        assert_eq!(Some(700.0), north.altitude);
+        // The opposite direction gives the same line
+        assert!(p.project_onto_line(&origin, 180.0).distance_squared(&north) < 1e-12);
+
+        let east = p.project_onto_line(&origin, 90.0);
+        assert!((east.north - origin.north).abs() < 1e-9);
+        assert!((east.east - 600_321.0).abs() < 1e-9);
+
+        let diagonal = p.project_onto_line(&origin, 45.0);
+        assert!((diagonal.east - 600_777.5).abs() < 1e-6);
+        assert!((diagonal.north - 200_777.5).abs() < 1e-6);
+    }
+
+    #[test]
+    #[cfg(feature = "libm")]
+    fn test_track_offsets() {
+        // Northbound segment
+        let a = Lv03::new(200_000.0, 600_000.0, 500.0).unwrap();
+        let b = Lv03::new(201_000.0, 600_000.0, 500.0).unwrap();
+        let left = Lv03::new_2d(200_300.0, 599_950.0).unwrap();
+        assert_eq!((300.0, 50.0), left.track_offsets(&a, &b));
+        let right = Lv03::new_2d(201_200.0, 600_040.0).unwrap();
+        assert_eq!((1200.0, -40.0), right.track_offsets(&a, &b));
+        // Reversing the direction of travel flips the side
+        assert_eq!((700.0, -50.0), left.track_offsets(&b, &a));
+
+        assert_eq!(
+            (0.0, 50.0),
+            left.track_offsets(&a.lerp(&b, 0.3), &a.lerp(&b, 0.3))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "libm")]
+    fn test_minimum_enclosing_circle() {
+        assert_eq!(None, minimum_enclosing_circle(&[]));
+
+        let a = Lv03::new(200_000.0, 600_000.0, 500.0).unwrap();
+        let (center, radius) = minimum_enclosing_circle(core::slice::from_ref(&a)).unwrap();
+        assert_eq!(Lv03::new_2d(200_000.0, 600_000.0).unwrap(), center);
+        assert_eq!(0.0, radius);
+
+        // Two points span the diameter
+        let b = Lv03::new(200_600.0, 600_800.0, 500.0).unwrap();
+        let (center, radius) = minimum_enclosing_circle(&[a.clone(), b.clone()]).unwrap();
+        assert_eq!(Lv03::new_2d(200_300.0, 600_400.0).unwrap(), center);
+        assert_eq!(500.0, radius);
+
+        // Right triangle, the hypotenuse is the diameter
+        let c = Lv03::new(200_000.0, 600_800.0, 500.0).unwrap();
+        let (center, radius) =
+            minimum_enclosing_circle(&[a.clone(), c.clone(), b.clone()]).unwrap();
+        assert!(center.distance_squared(&Lv03::new_2d(200_300.0, 600_400.0).unwrap()) < 1e-12);
+        assert!((radius - 500.0).abs() < 1e-9);
+
+        // Equilateral triangle needs all three points
+        let side = 1000.0;
+        let height = side * 3f64.sqrt() / 2.0;
+        let triangle = [
+            Lv03::new_2d(200_000.0, 600_000.0).unwrap(),
+            Lv03::new_2d(200_000.0, 600_000.0 + side).unwrap(),
+            Lv03::new_2d(200_000.0 + height, 600_000.0 + side / 2.0).unwrap(),
+        ];
+        let (center, radius) = minimum_enclosing_circle(&triangle).unwrap();
+        assert!((radius - side / 3f64.sqrt()).abs() < 1e-9);
+        assert!((center.east - 600_500.0).abs() < 1e-9);
+
+        // Collinear and interior points do not matter
+        let points = [
+            a.clone(),
+            a.lerp(&b, 0.25),
+            b.clone(),
+            a.lerp(&b, 0.5),
+            a.lerp(&c, 0.5),
+        ];
+        let (center, radius) = minimum_enclosing_circle(&points).unwrap();
+        assert!(center.distance_squared(&Lv03::new_2d(200_300.0, 600_400.0).unwrap()) < 1e-12);
+        assert!((radius - 500.0).abs() < 1e-9);
+    }
+
+    #[test]
+    #[cfg(feature = "libm")]
+    fn test_minimum_enclosing_circle_nearly_collinear() {
+        // The middle point is off the line by a rounding error only
+        let points = [
+            Lv03::new_2d(200_000.0, 600_000.0).unwrap(),
+            Lv03::new_2d(200_000.000_000_1, 650_000.0).unwrap(),
+            Lv03::new_2d(200_000.0, 700_000.0).unwrap(),
+        ];
+        let (center, radius) = minimum_enclosing_circle(&points).unwrap();
+        assert!((radius - 50_000.0).abs() < 1e-6);
+        assert!((center.east - 650_000.0).abs() < 1e-6);
+        assert!((center.north - 200_000.0).abs() < 1e-6);
+
+        // Exactly computed, the circle through these points would have a radius of 10^12 m
+        let circle = Circle::from_three((0.0, 0.0), (1000.0, 0.0), (2000.0, 1e-6));
+        assert!((circle.radius_squared - 1000.0 * 1000.0).abs() < 1e-3);
+        assert!((circle.center.0 - 1000.0).abs() < 1e-6);
+    }
+
+    #[test]
+    #[cfg(feature = "libm")]
+    fn test_minimum_enclosing_circle_ordered_arc() {
+        // Half circle of radius 10 km in order, like a track
+        let center = Lv03::new_2d(200_000.0, 600_000.0).unwrap();
+        let arc: [Lv03; 181] =
+            core::array::from_fn(|i| center.offset_by(i as f64 - 90.0, 10_000.0));
+        let (found, radius) = minimum_enclosing_circle(&arc).unwrap();
+        assert!((radius - 10_000.0).abs() < 1e-6);
+        assert!(found.distance_2d(&center) < 1e-6);
+    }
+
+    #[test]
+    #[cfg(feature = "libm")]
+    fn test_scrambled_order() {
+        for len in [1, 2, 3, 10, 12, 100, 181] {
+            let order = ScrambledOrder::new(len);
+            let mut seen = [false; 181];
+            for k in 0..len {
+                let index = order.index(k);
+                assert!(!seen[index]);
+                seen[index] = true;
+            }
+            assert!(seen[..len].iter().all(|&seen| seen));
+        }
+        // Neighbors in the input are not visited one after the other
+        let order = ScrambledOrder::new(100);
+        assert!(order.index(1).abs_diff(order.index(0)) > 1);
+    }
+
+    #[test]
+    #[cfg(feature = "libm")]
+    fn test_perpendicular_bisector() {
+        let a = Lv03::new(200_000.0, 600_000.0, 500.0).unwrap();
+        let b = Lv03::new(200_300.0, 600_400.0, 700.0).unwrap();
+        let (midpoint, bearing) = perpendicular_bisector(&a, &b).unwrap();
+        assert_eq!(
+            Lv03::new(200_150.0, 600_200.0, 600.0),
+            Some(midpoint.clone())
+        );
+        assert!((bearing - (a.bearing_to(&b) + 90.0)).abs() < 1e-9);
+        // Points on the bisector are equally far from both
+        let on_bisector = Lv03 {
+            north: midpoint.north + 1000.0 * bearing.to_radians().cos(),
+            east: midpoint.east + 1000.0 * bearing.to_radians().sin(),
+            altitude: None,
+        };
+        assert!((on_bisector.distance_2d(&a) - on_bisector.distance_2d(&b)).abs() < 1e-6);
+
+        // Northwards gives a bisector towards east, the reverse towards west
+        let c = Lv03::new_2d(201_000.0, 600_000.0).unwrap();
+        assert_eq!(90.0, perpendicular_bisector(&a, &c).unwrap().1);
+        assert_eq!(270.0, perpendicular_bisector(&c, &a).unwrap().1);
+
+        assert_eq!(None, perpendicular_bisector(&a, &a));
+    }
+
+    #[test]
+    fn test_segment_intersection() {
+        let a1 = Lv03::new(200_000.0, 600_000.0, 500.0).unwrap();
+        let a2 = Lv03::new(200_000.0, 601_000.0, 700.0).unwrap();
+        let b1 = Lv03::new(199_500.0, 600_250.0, 100.0).unwrap();
+        let b2 = Lv03::new(200_500.0, 600_250.0, 100.0).unwrap();
+        assert_eq!(
+            Lv03::new(200_000.0, 600_250.0, 550.0),
+            segment_intersection(&a1, &a2, &b1, &b2)
+        );
+        assert_eq!(
+            Lv03::new(200_000.0, 600_250.0, 100.0),
+            segment_intersection(&b1, &b2, &a1, &a2)
+        );
+
+        // Touching at an endpoint
+        assert_eq!(Some(a1.clone()), segment_intersection(&a1, &a2, &a1, &b1));
+
+        // Lines would cross, but not the segments
+        let b3 = Lv03::new(199_900.0, 600_250.0, 100.0).unwrap();
+        assert_eq!(None, segment_intersection(&a1, &a2, &b1, &b3));
+
+        // Parallel and collinear
+        let c1 = Lv03::new(200_100.0, 600_000.0, 500.0).unwrap();
+        let c2 = Lv03::new(200_100.0, 601_000.0, 500.0).unwrap();
+        assert_eq!(None, segment_intersection(&a1, &a2, &c1, &c2));
+        assert_eq!(None, segment_intersection(&a1, &a2, &a1, &a2));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_convex_hull() {
+        let sw = Lv03::new_2d(200_000.0, 600_000.0).unwrap();
+        let se = Lv03::new_2d(200_000.0, 601_000.0).unwrap();
+        let ne = Lv03::new_2d(201_000.0, 601_000.0).unwrap();
+        let nw = Lv03::new_2d(201_000.0, 600_000.0).unwrap();
+        let inside = Lv03::new_2d(200_400.0, 600_300.0).unwrap();
+        let on_edge = sw.lerp(&se, 0.5);
+        let points = [
+            inside.clone(),
+            ne.clone(),
+            sw.clone(),
+            on_edge,
+            nw.clone(),
+            se.clone(),
+            sw.clone(),
+        ];
+        assert_eq!([sw.clone(), se.clone(), ne, nw], convex_hull(&points)[..]);
+
+        // Degenerate inputs
+        assert!(convex_hull(&[]).is_empty());
+        let hull = convex_hull(&[sw.clone(), sw.clone()]);
+        assert_eq!(1, hull.len());
+        assert_eq!(sw, hull[0]);
+        assert_eq!(
+            [sw.clone(), se.clone()],
+            convex_hull(&[se.clone(), sw.lerp(&se, 0.3), sw.clone()])[..]
+        );
+    }
+}
@@ -0,0 +1,54 @@
+use crate::Lv03;
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String};
+use core::fmt;
+
+/// Swiss height reference systems
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HeightSystem {
+    /// Levelling network 1902, the heights used together with LV03
+    Ln02,
+    /// National height network 1995, the heights used together with LV95
+    Lhn95,
+}
+
+impl fmt::Display for HeightSystem {
+    /// Formats as the official abbreviation, e.g. `LN02`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let abbreviation = match self {
+            HeightSystem::Ln02 => "LN02",
+            HeightSystem::Lhn95 => "LHN95",
+        };
+        f.write_str(abbreviation)
+    }
+}
+
+impl Lv03 {
+    /// Altitude labeled with its height system, e.g. `542.8 m ü.M. (LN02)`. The value is not
+    /// converted between the systems. Returns none if the altitude is unknown
+    #[cfg(feature = "alloc")]
+    pub fn altitude_string(&self, system: HeightSystem) -> Option<String> {
+        self.altitude
+            .map(|altitude| format!("{} m ü.M. ({})", altitude, system))
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_altitude_string() {
+        let lv03 = Lv03::new(199_498.43, 600_421.43, 542.8).unwrap();
+        assert_eq!(
+            Some("542.8 m ü.M. (LN02)"),
+            lv03.altitude_string(HeightSystem::Ln02).as_deref()
+        );
+        assert_eq!(
+            Some("542.8 m ü.M. (LHN95)"),
+            lv03.altitude_string(HeightSystem::Lhn95).as_deref()
+        );
+        let lv03 = Lv03::new_2d(199_498.43, 600_421.43).unwrap();
+        assert_eq!(None, lv03.altitude_string(HeightSystem::Ln02));
+    }
+}
@@ -0,0 +1,131 @@
+//! French Lambert-93 projection (RGF93, EPSG:2154), a Lambert conformal conic projection
+//! on the GRS80 ellipsoid. RGF93 and WGS84 are treated as identical, they agree within a meter
+
+use crate::Wgs84;
+use core::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+/// First eccentricity of the GRS80 ellipsoid
+const GRS80_E: f64 = 0.081_819_191_042_815_79;
+/// Longitude of the central meridian in degrees
+const LAMBDA_0: f64 = 3.0;
+/// Exponent of the projection, derived from the standard parallels 44° and 49°
+const N: f64 = 0.725_607_765_053_267;
+/// Projection constant in meters
+const C: f64 = 11_754_255.426_096;
+/// False easting
+const X_S: f64 = 700_000.0;
+/// False northing plus the radius of the parallel of origin at 46.5°
+const Y_S: f64 = 12_655_612.049_876;
+
+/// Coordinate point in the French Lambert-93 projection
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lambert93 {
+    /// Easting in meters
+    pub x: f64,
+    /// Northing in meters
+    pub y: f64,
+    /// Altitude in meters, carried over unchanged from WGS84
+    pub altitude: Option<f64>,
+}
+
+impl Wgs84 {
+    /// Projects the position to Lambert-93. LV03 and LV95 points can be converted with
+    /// `to_wgs84` first
+    pub fn to_lambert93(&self) -> Lambert93 {
+        let phi = self.latitude_rad();
+        let e_sin = GRS80_E * libm::sin(phi);
+        // Isometric latitude
+        let latitude = libm::log(libm::tan(FRAC_PI_4 + phi / 2.0))
+            + GRS80_E / 2.0 * libm::log((1.0 - e_sin) / (1.0 + e_sin));
+        let radius = C * libm::exp(-N * latitude);
+        let gamma = N * (self.longitude - LAMBDA_0).to_radians();
+        Lambert93 {
+            x: X_S + radius * libm::sin(gamma),
+            y: Y_S - radius * libm::cos(gamma),
+            altitude: self.altitude,
+        }
+    }
+}
+
+impl Lambert93 {
+    pub fn to_wgs84(&self) -> Wgs84 {
+        let dx = self.x - X_S;
+        let dy = Y_S - self.y;
+        let radius = libm::sqrt(dx * dx + dy * dy);
+        let gamma = libm::atan2(dx, dy);
+        let latitude = -libm::log(radius / C) / N;
+
+        // Iterate the latitude from the isometric latitude
+        let exp_latitude = libm::exp(latitude);
+        let mut phi = 2.0 * libm::atan(exp_latitude) - FRAC_PI_2;
+        for _ in 0..10 {
+            let e_sin = GRS80_E * libm::sin(phi);
+            let next =
+                2.0 * libm::atan(
+                    exp_latitude * libm::pow((1.0 + e_sin) / (1.0 - e_sin), GRS80_E / 2.0),
+                ) - FRAC_PI_2;
+            let converged = (next - phi).abs() < 1e-14;
+            phi = next;
+            if converged {
+                break;
+            }
+        }
+        Wgs84 {
+            longitude: LAMBDA_0 + (gamma / N).to_degrees(),
+            latitude: phi.to_degrees(),
+            altitude: self.altitude,
+        }
+    }
+}
+
+impl From<Lambert93> for Wgs84 {
+    fn from(p: Lambert93) -> Self {
+        p.to_wgs84()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_origin() {
+        let origin = Wgs84 {
+            longitude: 3.0,
+            latitude: 46.5,
+            altitude: None,
+        };
+        let p = origin.to_lambert93();
+        assert!((p.x - 700_000.0).abs() < 1e-3);
+        assert!((p.y - 6_600_000.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_geneva() {
+        let geneva = Wgs84 {
+            longitude: 6.1432,
+            latitude: 46.2044,
+            altitude: Some(375.0),
+        };
+        let p = geneva.to_lambert93();
+        assert!((p.x - 942_293.96).abs() < 0.01);
+        assert!((p.y - 6_571_995.69).abs() < 0.01);
+        assert_eq!(Some(375.0), p.altitude);
+
+        let back = p.to_wgs84();
+        assert!((back.longitude - geneva.longitude).abs() < 1e-9);
+        assert!((back.latitude - geneva.latitude).abs() < 1e-9);
+        assert_eq!(geneva.altitude, back.altitude);
+    }
+
+    #[test]
+    fn test_from_lv03() {
+        // Geneva airport
+        let wgs = crate::Lv03::new(120_000.0, 498_000.0, 420.0)
+            .unwrap()
+            .to_wgs84();
+        let back: Wgs84 = wgs.to_lambert93().into();
+        assert!((back.longitude - wgs.longitude).abs() < 1e-9);
+        assert!((back.latitude - wgs.latitude).abs() < 1e-9);
+    }
+}
@@ -1,8 +1,99 @@
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String};
+use core::fmt;
+use core::ops::RangeInclusive;
+
 #[cfg(feature = "nav-types-conversion")]
 use nav_types::WGS84;
 
+#[macro_use]
+mod macros;
+
+mod batch;
+#[cfg(feature = "libm")]
+mod bessel;
+mod bounding_box;
+mod buffer;
+#[cfg(feature = "libm")]
+mod compass;
+mod domain;
+#[cfg(feature = "geo")]
+mod geo;
+mod geometry;
+mod height;
+#[cfg(feature = "libm")]
+mod lambert93;
+mod nmea;
+mod offset;
+mod quality;
+#[cfg(feature = "test-fixtures")]
+pub mod reference;
+mod reframe;
+#[cfg(feature = "regions")]
+mod region;
+#[cfg(feature = "libm")]
+mod sphere;
+mod summary;
+mod system;
+mod track;
+mod transform;
+#[cfg(feature = "libm")]
+mod web_mercator;
+#[cfg(feature = "serde")]
+pub mod wgs84_as_geojson;
+mod wmts;
+
+#[cfg(feature = "libm")]
+pub use batch::batch_roundtrip_errors;
+pub use batch::batch_to_wgs84_checksum;
+#[cfg(feature = "alloc")]
+pub use batch::partition_to_lv03;
+#[cfg(all(feature = "alloc", feature = "libm"))]
+pub use batch::{convert_dataset_to_wgs84, DatasetConversion};
+#[cfg(feature = "libm")]
+pub use bessel::bessel_to_wgs84_datum_shift;
+pub use bounding_box::Lv03BoundingBox;
+pub use buffer::PointBuffer;
+#[cfg(feature = "libm")]
+pub use compass::CompassDirection;
+pub use domain::segment_exits_domain;
+#[cfg(feature = "alloc")]
+pub use geometry::convex_hull;
+pub use geometry::{
+    are_collinear, polygon_area, polygon_centroid, polygon_centroid_wgs84, segment_intersection,
+    triangle_area, AreaAccumulator,
+};
+#[cfg(feature = "libm")]
+pub use geometry::{
+    minimum_enclosing_circle, perpendicular_bisector, triangle_area_3d, triangulate,
+};
+pub use height::HeightSystem;
+#[cfg(feature = "libm")]
+pub use lambert93::Lambert93;
+pub use offset::Lv03Offset;
+pub use quality::QualityTier;
+pub use reframe::{reframe, Coordinate, Frame};
+#[cfg(feature = "regions")]
+pub use region::Region;
+#[cfg(feature = "libm")]
+pub use sphere::wgs84_polygon_area;
+#[cfg(feature = "alloc")]
+pub use summary::bin_points;
+pub use summary::{summarize, PointSummary};
+pub use system::{reproject, CoordinateSystem};
+#[cfg(feature = "libm")]
+pub use track::{altitude_at_distance, total_turning};
+pub use track::{
+    elevation_profile, elevation_profile_with_threshold, filter_by_altitude, AltitudeTracker,
+};
+#[cfg(all(feature = "alloc", feature = "libm"))]
+pub use track::{resample_by_distance, segment_gradients, speeds};
+
 #[cfg(test)]
 #[macro_use(quickcheck)]
 extern crate quickcheck_macros;
@@ -14,14 +105,15 @@ pub struct Wgs84 {
     pub longitude: f64,
     /// Latitude in degrees
     pub latitude: f64,
-    /// Altitude in meters
-    pub altitude: f64,
+    /// Altitude in meters, `None` if the position has no known elevation
+    pub altitude: Option<f64>,
 }
 
 #[cfg(feature = "nav-types-conversion")]
-impl Into<WGS84<f64>> for Wgs84 {
-    fn into(self) -> WGS84<f64> {
-        WGS84::from_degrees_and_meters(self.latitude, self.longitude, self.altitude)
+impl From<Wgs84> for WGS84<f64> {
+    /// A missing altitude is mapped to 0 meters
+    fn from(p: Wgs84) -> Self {
+        WGS84::from_degrees_and_meters(p.latitude, p.longitude, p.altitude.unwrap_or(0.0))
     }
 }
 
@@ -31,7 +123,7 @@ impl From<WGS84<f64>> for Wgs84 {
         Wgs84 {
             latitude: p.latitude_degrees(),
             longitude: p.longitude_degrees(),
-            altitude: p.altitude(),
+            altitude: Some(p.altitude()),
         }
     }
 }
@@ -40,6 +132,62 @@ impl Wgs84 {
     /// For implementation details see the document
     /// "Näherungsformeln für die Transformation zwischen Schweizer Projektionskoordinaten und WGS84"
     pub fn to_lv03(&self) -> Option<Lv03> {
+        let p = self.project();
+        Lv03::from_parts(p.north, p.east, p.altitude, &Bounds::SWITZERLAND)
+    }
+
+    /// Like `to_lv03`, but points outside of the valid bounds are returned as well.
+    /// The flag is true if the point lies outside of the bounds, i.e. the result of the
+    /// approximation formulas is extrapolated and less reliable.
+    /// Returns none only if the conversion does not give finite coordinates
+    pub fn to_lv03_flagged(&self) -> (Option<Lv03>, bool) {
+        let p = self.project();
+        if let Some(valid) = Lv03::from_parts(p.north, p.east, p.altitude, &Bounds::SWITZERLAND) {
+            (Some(valid), false)
+        } else if p.north.is_finite() && p.east.is_finite() {
+            (Some(p), true)
+        } else {
+            (None, false)
+        }
+    }
+
+    /// Converts a position whose axis order is unknown. The values are first read as
+    /// `(latitude, longitude)` and then as `(longitude, latitude)`; the flag is true if the
+    /// swapped order gave the result. Returns none if both or none of the readings lie within
+    /// the swiss coordinate system, since the order is ambiguous then
+    pub fn to_lv03_autodetect(
+        lat_or_lon_a: f64,
+        lat_or_lon_b: f64,
+        altitude: f64,
+    ) -> Option<(Lv03, bool)> {
+        let as_given = Wgs84 {
+            latitude: lat_or_lon_a,
+            longitude: lat_or_lon_b,
+            altitude: Some(altitude),
+        };
+        let swapped = Wgs84 {
+            latitude: lat_or_lon_b,
+            longitude: lat_or_lon_a,
+            altitude: Some(altitude),
+        };
+        match (as_given.to_lv03(), swapped.to_lv03()) {
+            (Some(p), None) => Some((p, false)),
+            (None, Some(p)) => Some((p, true)),
+            _ => None,
+        }
+    }
+
+    /// Landmark closest to this position on the LV03 grid. Returns none if the position is
+    /// outside of the swiss coordinate system or there are no landmarks
+    pub fn nearest_landmark<'a>(&self, landmarks: &'a [Lv03]) -> Option<&'a Lv03> {
+        let p = self.to_lv03()?;
+        landmarks
+            .iter()
+            .min_by(|a, b| p.distance_squared(a).total_cmp(&p.distance_squared(b)))
+    }
+
+    /// Applies the approximation formulas without checking the result
+    fn project(&self) -> Lv03 {
         let phi = (3600.0 * self.latitude - 169_028.66) / 10_000.0;
         let phi_2 = phi * phi;
         let phi_3 = phi * phi_2;
@@ -56,13 +204,81 @@ impl Wgs84 {
             + 119.79 * phi_3;
         let y = e - 2_000_000.00;
         let x = n - 1_000_000.00;
-        let altitude = self.altitude - 49.55 + 2.73 * lambda + 6.94 * phi;
-        Lv03::new(x, y, altitude)
+        let altitude = self
+            .altitude
+            .map(|altitude| altitude - 49.55 + 2.73 * lambda + 6.94 * phi);
+        Lv03 {
+            north: x,
+            east: y,
+            altitude,
+        }
     }
 
     pub fn to_lv95(&self) -> Option<Lv95> {
         self.to_lv03().map(Into::into)
     }
+
+    /// Longitude in radians
+    pub fn longitude_rad(&self) -> f64 {
+        self.longitude.to_radians()
+    }
+
+    /// Latitude in radians
+    pub fn latitude_rad(&self) -> f64 {
+        self.latitude.to_radians()
+    }
+
+    /// Turns an orthometric height (above the geoid, e.g. taken from a map) into a height above
+    /// the WGS84 ellipsoid by adding the geoid undulation. Latitude and longitude are untouched
+    pub fn correct_height_to_ellipsoidal(self, undulation_m: f64) -> Self {
+        Wgs84 {
+            altitude: self.altitude.map(|altitude| altitude + undulation_m),
+            ..self
+        }
+    }
+
+    /// Inverse of `correct_height_to_ellipsoidal`
+    pub fn to_orthometric(self, undulation_m: f64) -> Self {
+        Wgs84 {
+            altitude: self.altitude.map(|altitude| altitude - undulation_m),
+            ..self
+        }
+    }
+
+    /// Link to this position on map.geo.admin.ch, see `Lv95::to_map_geo_admin_url`.
+    /// Returns none if the position is outside of the swiss coordinate system
+    #[cfg(feature = "alloc")]
+    pub fn to_map_geo_admin_url(&self, zoom: u8) -> Option<String> {
+        self.to_lv95().map(|p| p.to_map_geo_admin_url(zoom))
+    }
+}
+
+/// Extent of valid LV03 coordinates
+#[derive(Clone, Debug, PartialEq)]
+pub struct Bounds {
+    /// Valid range of the north coordinate
+    pub north: RangeInclusive<f64>,
+    /// Valid range of the east coordinate
+    pub east: RangeInclusive<f64>,
+}
+
+impl Bounds {
+    /// Extent of the swiss coordinate system, used by `Lv03::new`. Both ends of the ranges are
+    /// valid, so points clamped to the bounds are accepted by `Lv03::new`
+    pub const SWITZERLAND: Bounds = Bounds {
+        north: 70_000.0..=300_000.0,
+        east: 480_000.0..=850_000.0,
+    };
+
+    /// Extent of Liechtenstein, which also uses the swiss coordinate system
+    pub const LIECHTENSTEIN: Bounds = Bounds {
+        north: 212_000.0..=238_000.0,
+        east: 754_000.0..=767_000.0,
+    };
+
+    pub fn contains(&self, north: f64, east: f64) -> bool {
+        self.north.contains(&north) && self.east.contains(&east)
+    }
 }
 
 /// Coordinate point in the LV95 system (Landesvermessung 1995, CH1903+)
@@ -72,8 +288,8 @@ pub struct Lv95 {
     pub north: f64,
     /// Coordinate pointing east. (Y coordinate)
     pub east: f64,
-    /// Meters above sea level (Mediterranean Sea)
-    pub altitude: f64,
+    /// Meters above sea level (Mediterranean Sea), `None` if the point has no known elevation
+    pub altitude: Option<f64>,
 }
 
 /// Coordinate point in the LV03 system (Landesvermessung 1903, CH1903)
@@ -83,31 +299,97 @@ pub struct Lv03 {
     pub north: f64,
     /// Coordinate pointing east. (Y coordinate)
     pub east: f64,
-    /// Meters above sea level (Mediterranean Sea)
-    pub altitude: f64,
+    /// Meters above sea level (Mediterranean Sea), `None` if the point has no known elevation
+    pub altitude: Option<f64>,
 }
 
 impl Lv03 {
-    /// Can return none if the given coordinates do not lead to a valid representation in the swiss coordinate system
+    /// Can return none if the given coordinates do not lead to a valid representation in the swiss coordinate system.
+    /// Same as `new_strict`
     pub fn new(north: f64, east: f64, altitude: f64) -> Option<Self> {
-        let valid_north_range = 70_000.0..300_000.0;
-        let valid_east_range = 480_000.0..850_000.0;
+        Self::new_strict(north, east, altitude)
+    }
 
-        #[allow(clippy::if_same_then_else)]
-        if !valid_north_range.contains(&north) || !valid_east_range.contains(&east) {
-            None
-        } else if north > east {
-            // East coordinate must always be bigger than north
-            None
-        } else {
+    /// Returns none if any of the following conditions is violated:
+    /// - `north` lies within 70 000 to 300 000 (both ends inclusive), see `Bounds::SWITZERLAND`
+    /// - `east` lies within 480 000 to 850 000 (both ends inclusive), see `Bounds::SWITZERLAND`
+    /// - `east` is greater than or equal to `north`. Swiss east coordinates are always bigger
+    ///   than north coordinates, so a violation usually means the two were swapped.
+    ///   Since the two ranges above do not overlap, `east == north` is always rejected
+    ///   by the range checks
+    pub fn new_strict(north: f64, east: f64, altitude: f64) -> Option<Self> {
+        Self::new_within(north, east, altitude, &Bounds::SWITZERLAND)
+    }
+
+    /// Same as `new` but validates against the given bounds instead of `Bounds::SWITZERLAND`
+    pub fn new_within(north: f64, east: f64, altitude: f64, bounds: &Bounds) -> Option<Self> {
+        Self::from_parts(north, east, Some(altitude), bounds)
+    }
+
+    /// Same as `new` but for points without a known elevation
+    pub fn new_2d(north: f64, east: f64) -> Option<Self> {
+        Self::from_parts(north, east, None, &Bounds::SWITZERLAND)
+    }
+
+    /// Whether the east coordinate is greater than the north coordinate. This holds for every
+    /// point in Switzerland, so a violation usually means the coordinates were swapped.
+    /// Points created by `new` always satisfy it, for points created from the public fields it
+    /// can serve as a cheap debug assertion
+    pub fn invariant_holds(&self) -> bool {
+        self.east > self.north
+    }
+
+    /// Creates a point from raw LV95 coordinates. The LV95 offsets of 2 000 000 m east and
+    /// 1 000 000 m north are removed before the result is validated like in `new`
+    pub fn from_lv95(east_95: f64, north_95: f64, altitude: f64) -> Option<Self> {
+        Self::new(north_95 - 1_000_000.0, east_95 - 2_000_000.0, altitude)
+    }
+
+    /// Same point with the north coordinate replaced, none if the result is invalid like in `new`
+    pub fn with_north(self, north: f64) -> Option<Self> {
+        Self::from_parts(north, self.east, self.altitude, &Bounds::SWITZERLAND)
+    }
+
+    /// Same point with the east coordinate replaced, none if the result is invalid like in `new`
+    pub fn with_east(self, east: f64) -> Option<Self> {
+        Self::from_parts(self.north, east, self.altitude, &Bounds::SWITZERLAND)
+    }
+
+    /// Parses an ASCII record `east,north,altitude` without allocating, e.g. a line of a
+    /// CSV table stored in flash. The altitude may be omitted and whitespace around the
+    /// fields is ignored. Returns none for malformed records and invalid coordinates
+    pub fn parse_record(bytes: &[u8]) -> Option<Self> {
+        let mut fields = core::str::from_utf8(bytes).ok()?.split(',').map(str::trim);
+        let east = fields.next()?.parse().ok()?;
+        let north = fields.next()?.parse().ok()?;
+        let altitude = match fields.next() {
+            Some(altitude) => Some(altitude.parse().ok()?),
+            None => None,
+        };
+        if fields.next().is_some() {
+            return None;
+        }
+        Self::from_parts(north, east, altitude, &Bounds::SWITZERLAND)
+    }
+
+    fn from_parts(north: f64, east: f64, altitude: Option<f64>, bounds: &Bounds) -> Option<Self> {
+        if Self::is_valid(north, east, bounds) {
             Some(Lv03 {
                 north,
                 east,
                 altitude,
             })
+        } else {
+            None
         }
     }
 
+    /// The checks of `new_within`, see `new_strict`
+    pub(crate) fn is_valid(north: f64, east: f64, bounds: &Bounds) -> bool {
+        // East coordinate must never be smaller than north
+        bounds.contains(north, east) && east >= north
+    }
+
     /// For implementation details see the document
     /// "Näherungsformeln für die Transformation zwischen Schweizer Projektionskoordinaten und WGS84"
     pub fn to_wgs84(&self) -> Wgs84 {
@@ -123,7 +405,9 @@ impl Lv03 {
             - 0.002528 * x_2
             - 0.0447 * y_2 * x
             - 0.0140 * x_3;
-        let altitude = self.altitude + 49.55 - 12.6 * y - 22.64 * x;
+        let altitude = self
+            .altitude
+            .map(|altitude| altitude + 49.55 - 12.6 * y - 22.64 * x);
 
         let lambda = lambda * 100.0 / 36.0;
         let phi = phi * 100.0 / 36.0;
@@ -134,11 +418,371 @@ impl Lv03 {
         }
     }
 
+    /// Same as `to_wgs84`, but latitude and longitude are rounded to the given number of decimal
+    /// places and the altitude to decimeters, which avoids false precision in serialized output.
+    /// The approximation formulas are accurate to about a meter, so more than 5 decimal places
+    /// are rarely meaningful. At swiss latitudes one unit in the last place corresponds to
+    ///
+    /// | decimal places | latitude | longitude |
+    /// |----------------|----------|-----------|
+    /// | 3              | 111 m    | 76 m      |
+    /// | 4              | 11 m     | 7.6 m     |
+    /// | 5              | 1.1 m    | 0.76 m    |
+    /// | 6              | 0.11 m   | 0.08 m    |
+    ///
+    /// More than 12 decimal places are treated as 12
+    pub fn to_wgs84_rounded(&self, decimal_places: u8) -> Wgs84 {
+        let wgs = self.to_wgs84();
+        let decimal_places = decimal_places.min(12);
+        Wgs84 {
+            longitude: round_to(wgs.longitude, decimal_places),
+            latitude: round_to(wgs.latitude, decimal_places),
+            altitude: wgs.altitude.map(|altitude| round_to(altitude, 1)),
+        }
+    }
+
+    /// Same as the `Display` output with the given number of decimals for all values, e.g.
+    /// `format_with(0)` gives whole meters. Values are rounded to the nearest decimal like
+    /// `{:.N}` does, exact ties in the binary value are rounded to even
+    #[cfg(feature = "alloc")]
+    pub fn format_with(&self, decimals: usize) -> String {
+        format!("{:.*}", decimals, self)
+    }
+
+    /// The altitude difference only contributes if both points have an altitude
     pub fn distance_squared(&self, p: &Lv03) -> f64 {
+        let (d_north, d_east, d_altitude) = self.delta(p);
+        d_north * d_north + d_east * d_east + d_altitude * d_altitude
+    }
+
+    /// Differences `(d_north, d_east, d_altitude)` from this point to the other, e.g. to weight
+    /// the axes differently. The altitude difference is zero unless both points have an altitude
+    pub fn delta(&self, other: &Lv03) -> (f64, f64, f64) {
+        delta(
+            (self.north, self.east, self.altitude),
+            (other.north, other.east, other.altitude),
+        )
+    }
+
+    /// Whether the altitude lies within the range, false if the altitude is unknown
+    pub fn altitude_in_range(&self, range: RangeInclusive<f64>) -> bool {
+        self.altitude
+            .is_some_and(|altitude| range.contains(&altitude))
+    }
+
+    /// Creates a point from the relative notation of old maps, where `y_km` is the offset to the east
+    /// of 600 km and `x_km` the offset to the north of 200 km, both in kilometers
+    pub fn from_chyx(y_km: f64, x_km: f64, altitude: f64) -> Option<Self> {
+        Lv03::new(
+            200_000.0 + 1000.0 * x_km,
+            600_000.0 + 1000.0 * y_km,
+            altitude,
+        )
+    }
+
+    /// Inverse of `from_chyx`, returns `(y_km, x_km)`
+    pub fn to_chyx(&self) -> (f64, f64) {
+        (
+            (self.east - 600_000.0) / 1000.0,
+            (self.north - 200_000.0) / 1000.0,
+        )
+    }
+
+    /// Position within `Bounds::SWITZERLAND` as `(east_fraction, north_fraction)`, where 0 is the
+    /// western / southern and 1 the eastern / northern bound. Not clamped, so points
+    /// outside of the bounds give fractions outside of 0..=1
+    pub fn normalized_position(&self) -> (f64, f64) {
+        let bounds = Bounds::SWITZERLAND;
+        let (west, east) = (bounds.east.start(), bounds.east.end());
+        let (south, north) = (bounds.north.start(), bounds.north.end());
+        (
+            (self.east - west) / (east - west),
+            (self.north - south) / (north - south),
+        )
+    }
+
+    /// Inverse of `normalized_position`. Returns none for fractions outside of 0..=1
+    pub fn from_normalized(east_fraction: f64, north_fraction: f64, altitude: f64) -> Option<Self> {
+        let bounds = Bounds::SWITZERLAND;
+        let (west, east) = (bounds.east.start(), bounds.east.end());
+        let (south, north) = (bounds.north.start(), bounds.north.end());
+        Lv03::new(
+            south + north_fraction * (north - south),
+            west + east_fraction * (east - west),
+            altitude,
+        )
+    }
+
+    /// Linear interpolation between two points, `t = 0` gives `self` and `t = 1` gives `other`.
+    /// The altitude is only interpolated if both points have one. The result is not validated
+    pub fn lerp(&self, other: &Lv03, t: f64) -> Lv03 {
+        let altitude = match (self.altitude, other.altitude) {
+            (Some(a), Some(b)) => Some(a + t * (b - a)),
+            _ => None,
+        };
+        Lv03 {
+            north: self.north + t * (other.north - self.north),
+            east: self.east + t * (other.east - self.east),
+            altitude,
+        }
+    }
+
+    /// Point halfway between the two points, see `lerp`
+    pub fn midpoint(&self, other: &Lv03) -> Lv03 {
+        self.lerp(other, 0.5)
+    }
+
+    /// Weighted mean of the points, generalizing `lerp` to any number of points. The weights are
+    /// normalized by their sum and may be negative. The altitude is only blended if all points
+    /// have one. Returns none if the weights sum to zero. The result is not validated
+    pub fn weighted_blend(points: &[(Lv03, f64)]) -> Option<Lv03> {
+        let (first, _) = points.first()?;
+        // Relative to the first point to keep the sums small
+        let mut weight_sum = 0.0;
+        let mut north_sum = 0.0;
+        let mut east_sum = 0.0;
+        let mut altitude_sum = Some(0.0);
+        for (p, weight) in points {
+            weight_sum += weight;
+            north_sum += weight * (p.north - first.north);
+            east_sum += weight * (p.east - first.east);
+            altitude_sum = match (altitude_sum, p.altitude) {
+                (Some(sum), Some(altitude)) => Some(sum + weight * altitude),
+                _ => None,
+            };
+        }
+        if weight_sum == 0.0 {
+            return None;
+        }
+        Some(Lv03 {
+            north: first.north + north_sum / weight_sum,
+            east: first.east + east_sum / weight_sum,
+            altitude: altitude_sum.map(|sum| sum / weight_sum),
+        })
+    }
+
+    /// Centers of the eight 1 km cells surrounding the cell containing this point, ordered
+    /// clockwise starting north: N, NE, E, SE, S, SW, W, NW.
+    /// The centers have no altitude and are not validated, so cells beyond the border are included
+    pub fn neighbors_km(&self) -> [Lv03; 8] {
+        const OFFSETS: [(f64, f64); 8] = [
+            (1.0, 0.0),
+            (1.0, 1.0),
+            (0.0, 1.0),
+            (-1.0, 1.0),
+            (-1.0, 0.0),
+            (-1.0, -1.0),
+            (0.0, -1.0),
+            (1.0, -1.0),
+        ];
+        let north = floor(self.north / 1000.0) * 1000.0 + 500.0;
+        let east = floor(self.east / 1000.0) * 1000.0 + 500.0;
+        OFFSETS.map(|(d_north, d_east)| Lv03 {
+            north: north + 1000.0 * d_north,
+            east: east + 1000.0 * d_east,
+            altitude: None,
+        })
+    }
+
+    /// Identifier of the square grid cell of the given size containing this point.
+    /// The north cell index is stored in the upper and the east cell index in the lower 32 bits.
+    /// `cell_size_m` must not be zero
+    pub fn cell_id(&self, cell_size_m: u32) -> u64 {
+        let size = f64::from(cell_size_m);
+        let north_cell = floor(self.north / size) as u32;
+        let east_cell = floor(self.east / size) as u32;
+        (u64::from(north_cell) << 32) | u64::from(east_cell)
+    }
+
+    /// Center of the grid cell with the given identifier, see `cell_id`. It has no altitude
+    pub fn cell_center(id: u64, cell_size_m: u32) -> Lv03 {
+        let size = f64::from(cell_size_m);
+        let north_cell = (id >> 32) as f64;
+        let east_cell = (id & 0xFFFF_FFFF) as f64;
+        Lv03 {
+            north: (north_cell + 0.5) * size,
+            east: (east_cell + 0.5) * size,
+            altitude: None,
+        }
+    }
+
+    /// Compact binary encoding in 10 bytes: east and north as little endian `u32` millimeters
+    /// from the minimum of `Bounds::SWITZERLAND`, followed by the altitude as little endian
+    /// `u16` decimeters above -1000 m. Coordinates are rounded to the closest millimeter,
+    /// altitudes to the closest decimeter and clamped to -1000 m to 5553.4 m.
+    /// `u16::MAX` stands for a missing altitude
+    pub fn to_bytes(&self) -> [u8; 10] {
+        let to_mm = |value: f64, min: f64| floor((value - min) * 1000.0 + 0.5) as u32;
+        let east = to_mm(self.east, *Bounds::SWITZERLAND.east.start());
+        let north = to_mm(self.north, *Bounds::SWITZERLAND.north.start());
+        let altitude = match self.altitude {
+            Some(altitude) => {
+                floor((altitude + 1000.0) * 10.0 + 0.5).clamp(0.0, f64::from(u16::MAX - 1)) as u16
+            }
+            None => u16::MAX,
+        };
+        let mut bytes = [0; 10];
+        bytes[0..4].copy_from_slice(&east.to_le_bytes());
+        bytes[4..8].copy_from_slice(&north.to_le_bytes());
+        bytes[8..10].copy_from_slice(&altitude.to_le_bytes());
+        bytes
+    }
+
+    /// Decodes the encoding of `to_bytes`. Returns none if the decoded point is invalid
+    pub fn from_bytes(bytes: [u8; 10]) -> Option<Self> {
+        let [e0, e1, e2, e3, n0, n1, n2, n3, a0, a1] = bytes;
+        let east = f64::from(u32::from_le_bytes([e0, e1, e2, e3])) / 1000.0;
+        let north = f64::from(u32::from_le_bytes([n0, n1, n2, n3])) / 1000.0;
+        let altitude = match u16::from_le_bytes([a0, a1]) {
+            u16::MAX => None,
+            decimeters => Some(f64::from(decimeters) / 10.0 - 1000.0),
+        };
+        Self::from_parts(
+            north + *Bounds::SWITZERLAND.north.start(),
+            east + *Bounds::SWITZERLAND.east.start(),
+            altitude,
+            &Bounds::SWITZERLAND,
+        )
+    }
+
+    /// Point from coordinates and altitude in integer millimeters, as stored by some databases.
+    /// Returns none if the point is invalid, see `new`
+    pub fn from_millimeters(north_mm: i64, east_mm: i64, altitude_mm: i64) -> Option<Self> {
+        Lv03::new(
+            north_mm as f64 / 1000.0,
+            east_mm as f64 / 1000.0,
+            altitude_mm as f64 / 1000.0,
+        )
+    }
+
+    /// Coordinates and altitude `(north_mm, east_mm, altitude_mm)` in integer millimeters,
+    /// see `from_millimeters`. Each value is rounded to the closest millimeter, halves are
+    /// rounded up
+    pub fn to_millimeters(&self) -> (i64, i64, Option<i64>) {
+        let to_mm = |value: f64| floor(value * 1000.0 + 0.5) as i64;
+        (
+            to_mm(self.north),
+            to_mm(self.east),
+            self.altitude.map(to_mm),
+        )
+    }
+
+    /// Distance in meters, see `distance_squared`
+    #[cfg(feature = "libm")]
+    pub fn distance(&self, p: &Lv03) -> f64 {
+        libm::sqrt(self.distance_squared(p))
+    }
+
+    /// Distance in meters between this point and the result of converting it to WGS84 and back.
+    /// This is a measure for the accuracy of the approximation formulas at this point
+    #[cfg(feature = "libm")]
+    pub fn roundtrip_error(&self) -> f64 {
+        self.roundtrip_error_of(&self.to_wgs84())
+    }
+
+    /// `roundtrip_error` given the result of `to_wgs84`, to avoid converting twice
+    #[cfg(feature = "libm")]
+    pub(crate) fn roundtrip_error_of(&self, wgs84: &Wgs84) -> f64 {
+        self.distance(&wgs84.project())
+    }
+
+    /// Like `to_wgs84`, but returns none if the `roundtrip_error` exceeds `max_error_m`, i.e. the
+    /// approximation formulas are not accurate enough at this point. This costs an additional
+    /// conversion back to LV03
+    #[cfg(feature = "libm")]
+    pub fn to_wgs84_checked(&self, max_error_m: f64) -> Option<Wgs84> {
+        let wgs = self.to_wgs84();
+        if self.roundtrip_error_of(&wgs) > max_error_m {
+            None
+        } else {
+            Some(wgs)
+        }
+    }
+
+    /// Distance in meters on the grid plane, ignoring the altitude
+    #[cfg(feature = "libm")]
+    pub fn distance_2d(&self, p: &Lv03) -> f64 {
         let d_north = self.north - p.north;
         let d_east = self.east - p.east;
-        let d_altitude = self.altitude - p.altitude;
-        d_north * d_north + d_east * d_east + d_altitude * d_altitude
+        libm::sqrt(d_north * d_north + d_east * d_east)
+    }
+
+    /// Grid bearing to the other point in degrees, clockwise from grid north in the range 0..360
+    #[cfg(feature = "libm")]
+    pub fn bearing_to(&self, other: &Lv03) -> f64 {
+        let bearing = libm::atan2(other.east - self.east, other.north - self.north).to_degrees();
+        normalize_bearing(bearing)
+    }
+
+    /// Point scale factor of the projection, i.e. the ratio between a short distance on the grid
+    /// and the same distance on the ground. It is 1 on the east-west line through Bern and
+    /// grows with the distance to the north or south of it (about 1.0001 at the borders)
+    pub fn scale_factor(&self) -> f64 {
+        // Radius of the projection sphere
+        const RADIUS: f64 = 6_378_815.904;
+        // cosh(x / R), the series converges quickly since x / R < 0.03
+        let u = (self.north - 200_000.0) / RADIUS;
+        let u_2 = u * u;
+        1.0 + u_2 / 2.0 + u_2 * u_2 / 24.0
+    }
+
+    /// Meridian convergence in degrees: the angle from true north to grid north, measured clockwise.
+    /// It is zero on the meridian through Bern, positive east of it and negative west of it
+    /// (about ±2° at the borders). Derived from the approximation formulas
+    #[cfg(feature = "libm")]
+    pub fn meridian_convergence(&self) -> f64 {
+        let wgs84 = self.to_wgs84();
+        let phi = (3600.0 * wgs84.latitude - 169_028.66) / 10_000.0;
+        let lambda = (3600.0 * wgs84.longitude - 26_782.5) / 10_000.0;
+        // Direction of a meridian on the grid, from the derivatives of `Wgs84::to_lv03` by latitude
+        let d_east = -10938.51 * lambda - 0.72 * lambda * phi;
+        let d_north = 308_807.95 + 153.26 * phi - 194.56 * lambda * lambda + 359.37 * phi * phi;
+        -libm::atan2(d_east, d_north).to_degrees()
+    }
+
+    /// Angle in degrees by which true north is rotated clockwise from grid north at this point.
+    /// This is the negated `meridian_convergence`.
+    ///
+    /// On a map printed with grid north up, true north points this many degrees to the right
+    /// (negative: to the left). To draw a true-north arrow, rotate it clockwise by this angle.
+    ///
+    /// ```
+    /// use lv03::Lv03;
+    ///
+    /// // St. Gallen, east of Bern: true north points about 1.4° to the left of grid north
+    /// let st_gallen = Lv03::new(254_000.0, 746_000.0, 670.0).unwrap();
+    /// let angle = st_gallen.grid_north_to_true_north_angle();
+    /// assert!((angle + 1.4).abs() < 0.05);
+    /// ```
+    #[cfg(feature = "libm")]
+    pub fn grid_north_to_true_north_angle(&self) -> f64 {
+        -self.meridian_convergence()
+    }
+
+    /// Approximate distance on the ground (ellipsoid) in meters, ignoring the altitude.
+    /// The grid distance is corrected by the mean scale factor of the two endpoints,
+    /// for short distances this is indistinguishable from `distance_2d`
+    #[cfg(feature = "libm")]
+    pub fn ground_distance_to(&self, other: &Lv03) -> f64 {
+        let scale = (self.scale_factor() + other.scale_factor()) / 2.0;
+        self.distance_2d(other) / scale
+    }
+
+    /// Converts to WGS84 together with a radius in meters around the point expressed in degrees,
+    /// e.g. to draw an accuracy circle on a web map. The radius is approximated by dividing by
+    /// the length of a degree of latitude at the point, so it shrinks slightly towards north
+    #[cfg(feature = "libm")]
+    pub fn to_wgs84_with_radius(&self, radius_m: f64) -> (Wgs84, f64) {
+        // Semi-major axis and first eccentricity squared of the WGS84 ellipsoid
+        const A: f64 = 6_378_137.0;
+        const E2: f64 = 0.006_694_379_990_14;
+        let wgs84 = self.to_wgs84();
+        let sin_phi = libm::sin(wgs84.latitude_rad());
+        let w = 1.0 - E2 * sin_phi * sin_phi;
+        // Meridional radius of curvature
+        let m = A * (1.0 - E2) / (w * libm::sqrt(w));
+        let meters_per_degree = m.to_radians();
+        (wgs84, radius_m / meters_per_degree)
     }
 }
 
@@ -148,10 +792,170 @@ impl Lv95 {
         p.map(|p| p.into())
     }
 
+    /// Same as `new` but for points without a known elevation
+    pub fn new_2d(north: f64, east: f64) -> Option<Self> {
+        let p = Lv03::new_2d(north - 1_000_000.0, east - 2_000_000.0);
+        p.map(|p| p.into())
+    }
+
+    /// Validates the values like `new`, but also accepts LV95 coordinates whose leading
+    /// millions digit was dropped, i.e. values in LV03 range, and adds the LV95 offsets to them.
+    /// Returns none if neither reading is valid
+    pub fn repair_truncated(east: f64, north: f64, altitude: f64) -> Option<Lv95> {
+        Lv95::new(north, east, altitude)
+            .or_else(|| Lv03::new(north, east, altitude).map(Into::into))
+    }
+
     pub fn to_wgs84(&self) -> Wgs84 {
         let p03: Lv03 = self.clone().into();
         p03.to_wgs84()
     }
+
+    /// Same as `Lv03::distance_squared`. The differences are taken on the LV95 values directly,
+    /// so no precision is lost to the large offsets
+    pub fn distance_squared(&self, p: &Lv95) -> f64 {
+        distance_squared(
+            (self.north, self.east, self.altitude),
+            (p.north, p.east, p.altitude),
+        )
+    }
+
+    /// Link to this point on the swisstopo map viewer map.geo.admin.ch.
+    /// The coordinates are written with centimeter precision
+    #[cfg(feature = "alloc")]
+    pub fn to_map_geo_admin_url(&self, zoom: u8) -> String {
+        format!(
+            "https://map.geo.admin.ch/?E={:.2}&N={:.2}&zoom={}",
+            self.east, self.north, zoom
+        )
+    }
+}
+
+/// Interpolates linearly between the two points on the LV03 grid and converts the result to
+/// WGS84, see `Lv03::lerp`. Since the grid is metric this is more accurate than interpolating
+/// latitude and longitude directly
+pub fn lerp_to_wgs84(a: &Lv03, b: &Lv03, t: f64) -> Wgs84 {
+    a.lerp(b, t).to_wgs84()
+}
+
+/// Converts a grid of points spaced 10 km across `Bounds::SWITZERLAND` to WGS84 and back
+/// and returns the largest observed `Lv03::roundtrip_error` in meters.
+/// Allows downstream tests to check the precision of the conversions
+#[cfg(feature = "libm")]
+pub fn verify_precision() -> f64 {
+    const STEP: f64 = 10_000.0;
+    let bounds = Bounds::SWITZERLAND;
+    let mut max_error: f64 = 0.0;
+    let mut north = *bounds.north.start();
+    while north <= *bounds.north.end() {
+        let mut east = *bounds.east.start();
+        while east <= *bounds.east.end() {
+            let p = Lv03 {
+                north,
+                east,
+                altitude: Some(1000.0),
+            };
+            max_error = max_error.max(p.roundtrip_error());
+            east += STEP;
+        }
+        north += STEP;
+    }
+    max_error
+}
+
+/// Squared distance of two `(north, east, altitude)` points. The per-axis differences are taken
+/// before squaring to keep the precision for large coordinate values
+fn distance_squared(a: (f64, f64, Option<f64>), b: (f64, f64, Option<f64>)) -> f64 {
+    let (d_north, d_east, d_altitude) = delta(a, b);
+    d_north * d_north + d_east * d_east + d_altitude * d_altitude
+}
+
+/// Differences `b - a` per axis, the altitude difference is zero unless both are known
+fn delta(a: (f64, f64, Option<f64>), b: (f64, f64, Option<f64>)) -> (f64, f64, f64) {
+    let d_altitude = match (a.2, b.2) {
+        (Some(a), Some(b)) => b - a,
+        _ => 0.0,
+    };
+    (b.0 - a.0, b.1 - a.1, d_altitude)
+}
+
+/// Largest integer value less than or equal to `value`, `core` does not provide `f64::floor`
+fn floor(value: f64) -> f64 {
+    let truncated = value as i64 as f64;
+    if truncated > value {
+        truncated - 1.0
+    } else {
+        truncated
+    }
+}
+
+/// Wraps an angle in degrees into 0..360. Same as `rem_euclid`, which is not available in `core`
+#[cfg(feature = "libm")]
+fn normalize_bearing(angle: f64) -> f64 {
+    let wrapped = angle % 360.0;
+    if wrapped < 0.0 {
+        wrapped + 360.0
+    } else {
+        wrapped
+    }
+}
+
+/// Rounds half away from zero to the given number of decimal places
+fn round_to(value: f64, decimal_places: u8) -> f64 {
+    let scale = (0..decimal_places).fold(1.0, |scale, _| scale * 10.0);
+    let rounded = floor(value.abs() * scale + 0.5) / scale;
+    if value < 0.0 {
+        -rounded
+    } else {
+        rounded
+    }
+}
+
+/// Writes a number honoring the precision of the formatter, e.g. `{:.2}`
+fn write_number(f: &mut fmt::Formatter<'_>, value: f64) -> fmt::Result {
+    match f.precision() {
+        Some(precision) => write!(f, "{:.*}", precision, value),
+        None => write!(f, "{}", value),
+    }
+}
+
+/// Writes `first, second` followed by `, altitude m` if the altitude is known
+fn write_position(
+    f: &mut fmt::Formatter<'_>,
+    first: f64,
+    second: f64,
+    altitude: Option<f64>,
+) -> fmt::Result {
+    write_number(f, first)?;
+    f.write_str(", ")?;
+    write_number(f, second)?;
+    if let Some(altitude) = altitude {
+        f.write_str(", ")?;
+        write_number(f, altitude)?;
+        f.write_str(" m")?;
+    }
+    Ok(())
+}
+
+impl fmt::Display for Wgs84 {
+    /// Formats as `latitude, longitude` in degrees, followed by the altitude if known
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_position(f, self.latitude, self.longitude, self.altitude)
+    }
+}
+
+impl fmt::Display for Lv03 {
+    /// Formats as `east, north`, followed by the altitude if known
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_position(f, self.east, self.north, self.altitude)
+    }
+}
+
+impl fmt::Display for Lv95 {
+    /// Formats as `east, north`, followed by the altitude if known
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_position(f, self.east, self.north, self.altitude)
+    }
 }
 
 impl From<Lv95> for Lv03 {
@@ -189,18 +993,17 @@ impl From<Lv95> for Wgs84 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use oorandom;
 
     fn test_conversions(lv: &Lv03, wgs: &Wgs84) {
         let wgs_converted = lv.to_wgs84();
         assert!((wgs_converted.longitude - wgs.longitude).abs() < 0.001);
         assert!((wgs_converted.latitude - wgs.latitude).abs() < 0.001);
-        assert!((wgs_converted.altitude - wgs.altitude).abs() < 5.0);
+        assert!((wgs_converted.altitude.unwrap() - wgs.altitude.unwrap()).abs() < 5.0);
 
         let lv_converted = wgs.to_lv03().unwrap();
         assert!((lv_converted.east - lv.east).abs() < 2.0);
         assert!((lv_converted.north - lv.north).abs() < 2.0);
-        assert!((lv_converted.altitude - lv.altitude).abs() < 5.0);
+        assert!((lv_converted.altitude.unwrap() - lv.altitude.unwrap()).abs() < 5.0);
 
         test_roundtrip_wgs(lv);
         test_roundtrip_lv(lv);
@@ -227,7 +1030,7 @@ mod tests {
         Lv03 {
             north,
             east,
-            altitude,
+            altitude: Some(altitude),
         }
     }
 
@@ -241,7 +1044,7 @@ mod tests {
             let new_lv03: Lv03 = wgs84.to_lv03().unwrap();
             assert!((lv03.east - new_lv03.east).abs() < 5.0);
             assert!((lv03.north - new_lv03.north).abs() < 5.0);
-            assert!((lv03.altitude - new_lv03.altitude).abs() < 1.0);
+            assert!((lv03.altitude.unwrap() - new_lv03.altitude.unwrap()).abs() < 1.0);
             assert!(lv03.distance_squared(&new_lv03) < 30.0);
         }
     }
@@ -252,7 +1055,7 @@ mod tests {
         let wgs = Wgs84 {
             longitude: 7.44417,
             latitude: 46.94658,
-            altitude: 591.8,
+            altitude: Some(591.8),
         };
         test_conversions(&lv, &wgs);
     }
@@ -263,7 +1066,7 @@ mod tests {
         let wgs = Wgs84 {
             longitude: 7.65861,
             latitude: 45.97642,
-            altitude: 4532.9,
+            altitude: Some(4532.9),
         };
         test_conversions(&lv, &wgs);
     }
@@ -274,7 +1077,7 @@ mod tests {
         let wgs = Wgs84 {
             longitude: 8.730497076,
             latitude: 46.044130339,
-            altitude: 1050.0,
+            altitude: Some(1050.0),
         };
         test_conversions(&lv, &wgs);
     }
@@ -296,6 +1099,219 @@ mod tests {
         assert!(Lv03::new(600_000.0, 200_000.0, 500.0).is_none());
     }
 
+    #[test]
+    fn test_to_lv03_autodetect() {
+        let wgs = Wgs84 {
+            latitude: 46.946_57,
+            longitude: 7.444_17,
+            altitude: Some(592.3),
+        };
+        let lv03 = wgs.to_lv03().unwrap();
+        assert_eq!(
+            Some((lv03.clone(), false)),
+            Wgs84::to_lv03_autodetect(46.946_57, 7.444_17, 592.3)
+        );
+        assert_eq!(
+            Some((lv03, true)),
+            Wgs84::to_lv03_autodetect(7.444_17, 46.946_57, 592.3)
+        );
+        assert_eq!(None, Wgs84::to_lv03_autodetect(48.86, 2.35, 35.0));
+    }
+
+    #[test]
+    fn test_nearest_landmark() {
+        let landmarks = [
+            Lv03::new(199_498.43, 600_421.43, 542.8).unwrap(),
+            Lv03::new(91_673.72, 617_049.89, 4477.4).unwrap(),
+            Lv03::new(247_000.0, 683_000.0, 408.0).unwrap(),
+        ];
+        let fix = Wgs84 {
+            longitude: 7.6585,
+            latitude: 45.9763,
+            altitude: None,
+        };
+        assert_eq!(Some(&landmarks[1]), fix.nearest_landmark(&landmarks));
+        assert_eq!(None, fix.nearest_landmark(&[]));
+        let paris = Wgs84 {
+            longitude: 2.35,
+            latitude: 48.86,
+            altitude: None,
+        };
+        assert_eq!(None, paris.nearest_landmark(&landmarks));
+    }
+
+    #[test]
+    fn test_radians() {
+        let wgs = Wgs84 {
+            longitude: 90.0,
+            latitude: -45.0,
+            altitude: None,
+        };
+        assert_eq!(core::f64::consts::FRAC_PI_2, wgs.longitude_rad());
+        assert_eq!(-core::f64::consts::FRAC_PI_4, wgs.latitude_rad());
+    }
+
+    #[test]
+    fn test_to_lv03_flagged() {
+        let bundeshaus = Wgs84 {
+            longitude: 7.44417,
+            latitude: 46.94658,
+            altitude: Some(591.8),
+        };
+        assert_eq!((bundeshaus.to_lv03(), false), bundeshaus.to_lv03_flagged());
+
+        // Milan, south of the valid bounds
+        let milan = Wgs84 {
+            longitude: 9.19,
+            latitude: 45.464,
+            altitude: None,
+        };
+        assert_eq!(None, milan.to_lv03());
+        let (p, extrapolated) = milan.to_lv03_flagged();
+        assert!(extrapolated);
+        assert!(p.unwrap().north < 70_000.0);
+
+        let invalid = Wgs84 {
+            longitude: f64::NAN,
+            latitude: 45.464,
+            altitude: None,
+        };
+        assert_eq!((None, false), invalid.to_lv03_flagged());
+    }
+
+    #[test]
+    fn test_without_altitude() {
+        let lv = Lv03::new_2d(199_498.43, 600_421.43).unwrap();
+        assert_eq!(None, lv.altitude);
+        let wgs = lv.to_wgs84();
+        assert_eq!(None, wgs.altitude);
+        let back = wgs.to_lv03().unwrap();
+        assert_eq!(None, back.altitude);
+        assert!(lv.distance_squared(&back) < 1.0);
+
+        let lv95 = Lv95::new_2d(1_199_498.43, 2_600_421.43).unwrap();
+        assert!(lv.distance_squared(&lv95.into()) < 0.001);
+        assert!(Lv03::new_2d(600_000.0, 200_000.0).is_none());
+    }
+
+    #[test]
+    fn test_delta() {
+        let a = Lv03::new(200_000.0, 600_000.0, 500.0).unwrap();
+        let b = Lv03::new(200_003.0, 599_996.0, 512.0).unwrap();
+        assert_eq!((3.0, -4.0, 12.0), a.delta(&b));
+        assert_eq!((-3.0, 4.0, -12.0), b.delta(&a));
+        assert_eq!(169.0, a.distance_squared(&b));
+        let c = Lv03::new_2d(200_003.0, 599_996.0).unwrap();
+        assert_eq!((3.0, -4.0, 0.0), a.delta(&c));
+    }
+
+    #[test]
+    fn test_distance_without_altitude() {
+        let p1 = Lv03::new(200_000.0, 600_000.0, 500.0).unwrap();
+        let p2 = Lv03::new_2d(200_002.0, 600_000.0).unwrap();
+        assert_eq!(4.0, p1.distance_squared(&p2));
+    }
+
+    #[test]
+    fn test_display() {
+        extern crate std;
+        use std::format;
+
+        let lv03 = Lv03::new(199_498.43, 600_421.43, 542.8).unwrap();
+        assert_eq!("600421.43, 199498.43, 542.8 m", format!("{}", lv03));
+        assert_eq!("600421.4, 199498.4, 542.8 m", format!("{:.1}", lv03));
+        let lv03 = Lv03::new_2d(199_498.43, 600_421.43).unwrap();
+        assert_eq!("600421.43, 199498.43", format!("{}", lv03));
+        let lv95: Lv95 = lv03.into();
+        assert_eq!("2600421.43, 1199498.43", format!("{}", lv95));
+        let wgs = Wgs84 {
+            longitude: 7.44417,
+            latitude: 46.94658,
+            altitude: None,
+        };
+        assert_eq!("46.94658, 7.44417", format!("{}", wgs));
+    }
+
+    #[test]
+    fn test_invariant_holds() {
+        assert!(Lv03::new(199_498.43, 600_421.43, 542.8)
+            .unwrap()
+            .invariant_holds());
+        let swapped = Lv03 {
+            north: 600_421.43,
+            east: 199_498.43,
+            altitude: Some(542.8),
+        };
+        assert!(!swapped.invariant_holds());
+    }
+
+    #[test]
+    fn test_east_equals_north() {
+        // The valid ranges do not overlap, so equal coordinates are always rejected
+        for value in [70_000.0, 300_000.0, 480_000.0, 600_000.0] {
+            assert!(Lv03::new(value, value, 500.0).is_none());
+            assert!(Lv03::new_strict(value, value, 500.0).is_none());
+        }
+        assert!(Lv03::new_strict(299_999.0, 480_000.0, 500.0).is_some());
+        assert!(Lv03::new_strict(480_000.0, 299_999.0, 500.0).is_none());
+    }
+
+    #[test]
+    fn test_bounds() {
+        // Both ends of the ranges are accepted, including the upper ends
+        assert!(Lv03::new(300_000.0, 850_000.0, 500.0).is_some());
+        assert!(Lv03::new(70_000.0, 480_000.0, 500.0).is_some());
+        assert!(Lv03::new(300_000.1, 850_000.0, 500.0).is_none());
+        assert!(Lv03::new(200_000.0, 850_000.1, 500.0).is_none());
+
+        // Vaduz
+        let vaduz = Lv03::new_within(222_800.0, 757_800.0, 455.0, &Bounds::LIECHTENSTEIN);
+        assert!(vaduz.is_some());
+        assert_eq!(Lv03::new(222_800.0, 757_800.0, 455.0), vaduz);
+        assert!(Lv03::new_within(199_498.43, 600_421.43, 542.8, &Bounds::LIECHTENSTEIN).is_none());
+
+        let wide = Bounds {
+            north: 0.0..=400_000.0,
+            east: 400_000.0..=900_000.0,
+        };
+        assert!(Lv03::new_within(60_000.0, 870_000.0, 500.0, &wide).is_some());
+        assert!(Lv03::new(60_000.0, 870_000.0, 500.0).is_none());
+    }
+
+    #[test]
+    fn test_height_correction() {
+        let wgs = Wgs84 {
+            longitude: 7.44417,
+            latitude: 46.94658,
+            altitude: Some(542.8),
+        };
+        let ellipsoidal = wgs.clone().correct_height_to_ellipsoidal(49.0);
+        assert_eq!(Some(591.8), ellipsoidal.altitude);
+        assert_eq!(wgs.longitude, ellipsoidal.longitude);
+        assert_eq!(wgs.latitude, ellipsoidal.latitude);
+        assert_eq!(wgs, ellipsoidal.to_orthometric(49.0));
+
+        let flat = Wgs84 {
+            altitude: None,
+            ..wgs
+        };
+        assert_eq!(flat, flat.clone().correct_height_to_ellipsoidal(49.0));
+    }
+
+    #[test]
+    fn test_macro() {
+        let p = lv03!(east = 600_000, north = 200_000, alt = 540);
+        assert_eq!(Lv03::new(200_000.0, 600_000.0, 540.0), Some(p));
+        let p = lv03!(east = 600_421.43, north = 199_498.43);
+        assert_eq!(Lv03::new_2d(199_498.43, 600_421.43), Some(p));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid LV03 coordinates")]
+    fn test_macro_swapped() {
+        lv03!(east = 200_000, north = 600_000, alt = 540);
+    }
+
     #[test]
     fn test_distance() {
         let p1 = Lv03::new(200_000.0, 600_000.0, 500.0).unwrap();
@@ -303,6 +1319,307 @@ mod tests {
         assert_eq!(4.0, p1.distance_squared(&p2));
     }
 
+    #[test]
+    fn test_to_wgs84_rounded() {
+        let lv = Lv03::new(199_498.43, 600_421.43, 542.8).unwrap();
+        let wgs = lv.to_wgs84();
+        let rounded = lv.to_wgs84_rounded(5);
+        assert!((rounded.latitude - wgs.latitude).abs() <= 0.5e-5);
+        assert!((rounded.longitude - wgs.longitude).abs() <= 0.5e-5);
+        assert_eq!(rounded.latitude, (rounded.latitude * 1e5).round() / 1e5);
+        assert_eq!(rounded.longitude, (rounded.longitude * 1e5).round() / 1e5);
+        let altitude = rounded.altitude.unwrap();
+        assert_eq!(altitude, (altitude * 10.0).round() / 10.0);
+        assert!((altitude - wgs.altitude.unwrap()).abs() <= 0.05);
+
+        let precise = lv.to_wgs84_rounded(u8::MAX);
+        assert!((precise.latitude - wgs.latitude).abs() < 1e-12);
+        assert_eq!(
+            None,
+            Lv03::new_2d(199_498.43, 600_421.43)
+                .unwrap()
+                .to_wgs84_rounded(5)
+                .altitude
+        );
+    }
+
+    #[test]
+    fn test_from_lv95() {
+        let lv03 = Lv03::from_lv95(2_600_421.43, 1_199_498.43, 542.8).unwrap();
+        assert!(lv03.distance_squared(&Lv03::new(199_498.43, 600_421.43, 542.8).unwrap()) < 1e-12);
+        let lv95 = Lv95::new(1_199_498.43, 2_600_421.43, 542.8).unwrap();
+        assert_eq!(lv03, lv95.into());
+        // LV03 values are rejected
+        assert_eq!(None, Lv03::from_lv95(600_421.43, 199_498.43, 542.8));
+        // Swapped
+        assert_eq!(None, Lv03::from_lv95(1_199_498.43, 2_600_421.43, 542.8));
+    }
+
+    #[test]
+    fn test_with_north_east() {
+        let lv03 = Lv03::new(199_498.43, 600_421.43, 542.8).unwrap();
+        assert_eq!(
+            Lv03::new(200_000.0, 600_421.43, 542.8),
+            lv03.clone().with_north(200_000.0)
+        );
+        assert_eq!(
+            Lv03::new(199_498.43, 849_000.0, 542.8),
+            lv03.clone().with_east(849_000.0)
+        );
+        assert_eq!(None, lv03.clone().with_east(851_000.0));
+        assert_eq!(None, lv03.clone().with_north(60_000.0));
+        let lv03 = Lv03::new_2d(199_498.43, 600_421.43).unwrap();
+        assert_eq!(None, lv03.with_east(500_000.0).unwrap().altitude);
+    }
+
+    #[test]
+    fn test_parse_record() {
+        assert_eq!(
+            Lv03::new(199_498.43, 600_421.43, 542.8),
+            Lv03::parse_record(b"600421.43,199498.43,542.8\r\n")
+        );
+        assert_eq!(
+            Lv03::new_2d(199_498.43, 600_421.43),
+            Lv03::parse_record(b"600421.43, 199498.43")
+        );
+        assert!(Lv03::parse_record(b"600421.43;199498.43;542.8").is_none());
+        assert!(Lv03::parse_record(b"600421.43,199498.43,542.8,1").is_none());
+        assert!(Lv03::parse_record(b"600421.43,,542.8").is_none());
+        assert!(Lv03::parse_record(b"199498.43,600421.43,542.8").is_none());
+        assert!(Lv03::parse_record(&[0xff, b',', b'1']).is_none());
+        assert!(Lv03::parse_record(b"").is_none());
+    }
+
+    #[test]
+    fn test_chyx() {
+        let p = Lv03::from_chyx(0.421, -0.502, 542.8).unwrap();
+        assert!((p.east - 600_421.0).abs() < 1e-6);
+        assert!((p.north - 199_498.0).abs() < 1e-6);
+        let (y, x) = p.to_chyx();
+        assert!((y - 0.421).abs() < 1e-9);
+        assert!((x + 0.502).abs() < 1e-9);
+        assert!(Lv03::from_chyx(300.0, 0.0, 500.0).is_none());
+    }
+
+    #[test]
+    fn test_normalized_position() {
+        let south_west = Lv03::new(70_000.0, 480_000.0, 500.0).unwrap();
+        assert_eq!((0.0, 0.0), south_west.normalized_position());
+        let north_east = Lv03::new(300_000.0, 850_000.0, 500.0).unwrap();
+        assert_eq!((1.0, 1.0), north_east.normalized_position());
+        let p = Lv03::new(185_000.0, 665_000.0, 500.0).unwrap();
+        assert_eq!((0.5, 0.5), p.normalized_position());
+        assert_eq!(Some(p), Lv03::from_normalized(0.5, 0.5, 500.0));
+        assert_eq!(None, Lv03::from_normalized(1.1, 0.5, 500.0));
+    }
+
+    #[test]
+    fn test_lerp() {
+        let p1 = Lv03::new(200_000.0, 600_000.0, 500.0).unwrap();
+        let p2 = Lv03::new(201_000.0, 602_000.0, 700.0).unwrap();
+        assert_eq!(p1, p1.lerp(&p2, 0.0));
+        assert_eq!(p2, p1.lerp(&p2, 1.0));
+        assert_eq!(
+            Lv03::new(200_500.0, 601_000.0, 600.0),
+            Some(p1.lerp(&p2, 0.5))
+        );
+        let p3 = Lv03::new_2d(201_000.0, 602_000.0).unwrap();
+        assert_eq!(None, p1.lerp(&p3, 0.5).altitude);
+    }
+
+    #[test]
+    fn test_weighted_blend() {
+        let p1 = Lv03::new(200_000.0, 600_000.0, 500.0).unwrap();
+        let p2 = Lv03::new(201_000.0, 602_000.0, 700.0).unwrap();
+        let p3 = Lv03::new_2d(202_000.0, 602_500.0).unwrap();
+        assert_eq!(
+            Some(p1.midpoint(&p2)),
+            Lv03::weighted_blend(&[(p1.clone(), 2.0), (p2.clone(), 2.0)])
+        );
+        assert_eq!(
+            Some(p1.lerp(&p2, 0.25)),
+            Lv03::weighted_blend(&[(p1.clone(), 3.0), (p2.clone(), 1.0)])
+        );
+        assert_eq!(
+            Lv03::new_2d(201_000.0, 601_500.0),
+            Lv03::weighted_blend(&[(p1.clone(), 1.0), (p2.clone(), 1.0), (p3.clone(), 1.0)])
+        );
+        assert_eq!(None, Lv03::weighted_blend(&[(p1.clone(), 1.0), (p2, -1.0)]));
+        assert_eq!(None, Lv03::weighted_blend(&[]));
+    }
+
+    #[test]
+    fn test_lerp_to_wgs84() {
+        let a = Lv03::new(199_498.43, 600_421.43, 542.8).unwrap();
+        let b = Lv03::new(91_673.72, 617_049.89, 4477.4).unwrap();
+        assert_eq!(a.midpoint(&b).to_wgs84(), lerp_to_wgs84(&a, &b, 0.5));
+        assert_eq!(a.to_wgs84(), lerp_to_wgs84(&a, &b, 0.0));
+        assert_eq!(b.to_wgs84(), lerp_to_wgs84(&a, &b, 1.0));
+    }
+
+    #[test]
+    fn test_neighbors_km() {
+        let p = Lv03::new(199_498.43, 600_421.43, 542.8).unwrap();
+        let neighbors = p.neighbors_km();
+        assert_eq!(Lv03::new_2d(200_500.0, 600_500.0).unwrap(), neighbors[0]);
+        assert_eq!(Lv03::new_2d(200_500.0, 601_500.0).unwrap(), neighbors[1]);
+        assert_eq!(Lv03::new_2d(199_500.0, 601_500.0).unwrap(), neighbors[2]);
+        assert_eq!(Lv03::new_2d(198_500.0, 601_500.0).unwrap(), neighbors[3]);
+        assert_eq!(Lv03::new_2d(198_500.0, 600_500.0).unwrap(), neighbors[4]);
+        assert_eq!(Lv03::new_2d(198_500.0, 599_500.0).unwrap(), neighbors[5]);
+        assert_eq!(Lv03::new_2d(199_500.0, 599_500.0).unwrap(), neighbors[6]);
+        assert_eq!(Lv03::new_2d(200_500.0, 599_500.0).unwrap(), neighbors[7]);
+
+        // Cells beyond the border are still returned
+        let edge = Lv03::new(70_100.0, 480_100.0, 500.0).unwrap();
+        assert_eq!(69_500.0, edge.neighbors_km()[4].north);
+        assert_eq!(479_500.0, edge.neighbors_km()[6].east);
+    }
+
+    #[test]
+    fn test_cell_id() {
+        let p = Lv03::new(199_498.43, 600_421.43, 542.8).unwrap();
+        assert_eq!((199 << 32) | 600, p.cell_id(1000));
+        assert_eq!(
+            Lv03::new_2d(199_500.0, 600_500.0).unwrap(),
+            Lv03::cell_center(p.cell_id(1000), 1000)
+        );
+        let q = Lv03::new(199_000.0, 600_999.9, 500.0).unwrap();
+        assert_eq!(p.cell_id(1000), q.cell_id(1000));
+        assert_ne!(p.cell_id(100), q.cell_id(100));
+        assert_eq!(
+            Lv03::new_2d(199_450.0, 600_450.0).unwrap(),
+            Lv03::cell_center(p.cell_id(100), 100)
+        );
+    }
+
+    #[test]
+    fn test_floor() {
+        assert_eq!(1.0, floor(1.5));
+        assert_eq!(-2.0, floor(-1.5));
+        assert_eq!(-1.0, floor(-1.0));
+        assert_eq!(0.0, floor(0.0));
+        assert_eq!(1.25, round_to(1.245_01, 2));
+        assert_eq!(-1.25, round_to(-1.245_01, 2));
+        assert_eq!(2.0, round_to(1.5, 0));
+    }
+
+    #[test]
+    fn test_scale_factor() {
+        let bern = Lv03::new(200_000.0, 600_000.0, 500.0).unwrap();
+        assert_eq!(1.0, bern.scale_factor());
+        let north = Lv03::new(290_000.0, 600_000.0, 500.0).unwrap();
+        let south = Lv03::new(110_000.0, 600_000.0, 500.0).unwrap();
+        assert!((north.scale_factor() - 1.0000995).abs() < 1e-7);
+        assert_eq!(north.scale_factor(), south.scale_factor());
+    }
+
+    #[test]
+    #[cfg(feature = "libm")]
+    fn test_meridian_convergence() {
+        let bern = Lv03::new(200_000.0, 600_000.0, 500.0).unwrap();
+        assert!(bern.meridian_convergence().abs() < 0.01);
+
+        for east in [500_000.0, 700_000.0, 820_000.0] {
+            let p = Lv03::new(150_000.0, east, 500.0).unwrap();
+            let wgs = p.to_wgs84();
+            // Common approximation (lambda - lambda_0) * sin(phi)
+            let approximation = (wgs.longitude - 7.439583) * wgs.latitude.to_radians().sin();
+            assert!((p.meridian_convergence() - approximation).abs() < 0.05);
+            assert_eq!(
+                -p.meridian_convergence(),
+                p.grid_north_to_true_north_angle()
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "libm")]
+    fn test_roundtrip_error() {
+        let bundeshaus = Lv03::new(199_498.43, 600_421.43, 542.8).unwrap();
+        assert!(bundeshaus.roundtrip_error() < 0.5);
+        let corner = Lv03::new(299_000.0, 849_000.0, 1000.0).unwrap();
+        assert!(corner.roundtrip_error() > bundeshaus.roundtrip_error());
+        let max_error = verify_precision();
+        assert!(max_error >= corner.roundtrip_error());
+        // Worst at the corners of the bounds, which lie outside of Switzerland
+        assert!(max_error < 5.0);
+    }
+
+    #[test]
+    #[cfg(feature = "libm")]
+    fn test_bearing() {
+        let p = Lv03::new(200_000.0, 600_000.0, 500.0).unwrap();
+        let north = Lv03::new(201_000.0, 600_000.0, 500.0).unwrap();
+        let east = Lv03::new(200_000.0, 601_000.0, 500.0).unwrap();
+        let south_west = Lv03::new(199_000.0, 599_000.0, 500.0).unwrap();
+        assert_eq!(0.0, p.bearing_to(&north));
+        assert_eq!(90.0, p.bearing_to(&east));
+        assert_eq!(180.0, north.bearing_to(&p));
+        assert_eq!(270.0, east.bearing_to(&p));
+        assert!((p.bearing_to(&south_west) - 225.0).abs() < 1e-9);
+
+        assert_eq!(350.0, normalize_bearing(-10.0));
+        assert_eq!(10.0, normalize_bearing(730.0));
+        assert_eq!(0.0, normalize_bearing(360.0));
+    }
+
+    #[test]
+    #[cfg(feature = "libm")]
+    fn test_to_wgs84_with_radius() {
+        let south = Lv03::new(80_000.0, 720_000.0, 500.0).unwrap();
+        let north = Lv03::new(290_000.0, 720_000.0, 500.0).unwrap();
+        let (wgs, radius) = south.to_wgs84_with_radius(100.0);
+        assert_eq!(south.to_wgs84(), wgs);
+        // About 111.1 km per degree of latitude
+        assert!((radius - 100.0 / 111_140.0).abs() < 1e-7);
+        let (_, radius_north) = north.to_wgs84_with_radius(100.0);
+        assert!(radius_north > 0.0);
+        assert!(radius_north < radius);
+        assert_eq!(0.0, north.to_wgs84_with_radius(0.0).1);
+    }
+
+    #[test]
+    #[cfg(feature = "libm")]
+    fn test_ground_distance() {
+        let west = Lv03::new(290_000.0, 500_000.0, 500.0).unwrap();
+        let east = Lv03::new(290_000.0, 800_000.0, 500.0).unwrap();
+        assert_eq!(300_000.0, west.distance(&east));
+        let correction = west.distance_2d(&east) - west.ground_distance_to(&east);
+        assert!((correction - 29.85).abs() < 0.1);
+
+        let west = Lv03::new(200_000.0, 500_000.0, 500.0).unwrap();
+        let east = Lv03::new(200_000.0, 800_000.0, 500.0).unwrap();
+        assert_eq!(west.distance_2d(&east), west.ground_distance_to(&east));
+    }
+
+    #[test]
+    fn test_repair_truncated() {
+        let lv95 = Lv95::new(1_199_498.43, 2_600_421.43, 542.8).unwrap();
+        assert_eq!(
+            Some(lv95.clone()),
+            Lv95::repair_truncated(2_600_421.43, 1_199_498.43, 542.8)
+        );
+        let repaired = Lv95::repair_truncated(600_421.43, 199_498.43, 542.8).unwrap();
+        assert!(repaired.distance_squared(&lv95) < 1e-12);
+        assert_eq!(
+            None,
+            Lv95::repair_truncated(1_600_421.43, 199_498.43, 542.8)
+        );
+        assert_eq!(None, Lv95::repair_truncated(199_498.43, 600_421.43, 542.8));
+    }
+
+    #[test]
+    fn test_lv95_distance() {
+        let p1 = Lv95::new(1_200_000.0, 2_600_000.0, 500.0).unwrap();
+        let p2 = Lv95::new(1_200_000.0, 2_600_001.0, 500.0).unwrap();
+        assert_eq!(1.0, p1.distance_squared(&p2));
+        let p3 = Lv95::new_2d(1_200_001.0, 2_600_000.0).unwrap();
+        assert_eq!(1.0, p1.distance_squared(&p3));
+        let p4 = Lv95::new(1_200_000.0, 2_600_000.0, 502.0).unwrap();
+        assert_eq!(4.0, p1.distance_squared(&p4));
+    }
+
     #[test]
     fn test_lv_conversion() {
         let p1 = Lv03::new(200_000.0, 600_000.0, 500.0).unwrap();
@@ -313,13 +1630,95 @@ mod tests {
         assert_eq!(p1.north + 1_000_000.0, p2.north);
     }
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_map_geo_admin_url() {
+        let lv95 = Lv95::new(1_199_498.43, 2_600_421.43, 542.8).unwrap();
+        assert_eq!(
+            "https://map.geo.admin.ch/?E=2600421.43&N=1199498.43&zoom=10",
+            lv95.to_map_geo_admin_url(10)
+        );
+
+        let wgs = lv95.to_wgs84();
+        let url = wgs.to_map_geo_admin_url(5).unwrap();
+        assert!(url.starts_with("https://map.geo.admin.ch/?E=2600"));
+        assert!(url.ends_with("&zoom=5"));
+
+        let outside = Wgs84 {
+            longitude: 0.0,
+            latitude: 0.0,
+            altitude: None,
+        };
+        assert_eq!(None, outside.to_map_geo_admin_url(5));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_format_with() {
+        let lv03 = Lv03::new(199_498.43, 600_421.63, 542.8).unwrap();
+        assert_eq!("600422, 199498, 543 m", lv03.format_with(0));
+        assert_eq!("600421.630, 199498.430, 542.800 m", lv03.format_with(3));
+        let lv03 = Lv03::new_2d(199_498.43, 600_421.63).unwrap();
+        assert_eq!("600421.6, 199498.4", lv03.format_with(1));
+    }
+
+    #[test]
+    #[cfg(feature = "libm")]
+    fn test_to_wgs84_checked() {
+        let bern = Lv03::new(199_498.43, 600_421.43, 542.8).unwrap();
+        assert_eq!(Some(bern.to_wgs84()), bern.to_wgs84_checked(1.0));
+
+        let corner = Lv03::new(70_000.0, 850_000.0, 0.0).unwrap();
+        let error = corner.roundtrip_error();
+        assert!(error > 0.0);
+        assert_eq!(None, corner.to_wgs84_checked(error / 2.0));
+        assert_eq!(Some(corner.to_wgs84()), corner.to_wgs84_checked(error));
+    }
+
+    #[test]
+    fn test_millimeters() {
+        let p = Lv03::from_millimeters(199_498_430, 600_421_430, 542_800).unwrap();
+        assert_eq!(Lv03::new(199_498.43, 600_421.43, 542.8), Some(p.clone()));
+        assert_eq!(
+            (199_498_430, 600_421_430, Some(542_800)),
+            p.to_millimeters()
+        );
+
+        let p = Lv03::new(199_498.432_5, 600_421.432_4, -0.000_6).unwrap();
+        assert_eq!((199_498_433, 600_421_432, Some(-1)), p.to_millimeters());
+        assert_eq!(
+            (200_000_000, 600_000_000, None),
+            Lv03::new_2d(200_000.0, 600_000.0).unwrap().to_millimeters()
+        );
+        assert_eq!(None, Lv03::from_millimeters(0, 0, 0));
+    }
+
+    #[test]
+    fn test_bytes_roundtrip() {
+        let p = Lv03::new(199_498.432_1, 600_421.432_9, 542.83).unwrap();
+        let decoded = Lv03::from_bytes(p.to_bytes()).unwrap();
+        assert!((decoded.north - p.north).abs() <= 0.0005);
+        assert!((decoded.east - p.east).abs() <= 0.0005);
+        assert!((decoded.altitude.unwrap() - 542.8).abs() < 1e-9);
+
+        let p = Lv03::new_2d(70_000.0, 850_000.0).unwrap();
+        let decoded = Lv03::from_bytes(p.to_bytes()).unwrap();
+        assert_eq!(p, decoded);
+
+        let deep = Lv03::new(200_000.0, 600_000.0, -2000.0).unwrap();
+        let decoded = Lv03::from_bytes(deep.to_bytes()).unwrap();
+        assert_eq!(Some(-1000.0), decoded.altitude);
+
+        assert_eq!(None, Lv03::from_bytes([0xFF; 10]));
+    }
+
     #[test]
     #[cfg(feature = "nav-types-conversion")]
     fn test_nav_types_conversion() {
         let wgs = Wgs84 {
             longitude: 8.730497076,
             latitude: 46.044130339,
-            altitude: 542.8,
+            altitude: Some(542.8),
         };
         let nav_type: WGS84<f64> = wgs.clone().into();
         let back: Wgs84 = Wgs84::from(nav_type);
@@ -327,11 +1726,11 @@ mod tests {
         assert_eq!(wgs, back);
         assert_eq!(wgs.longitude, nav_type.longitude_degrees());
         assert_eq!(wgs.latitude, nav_type.latitude_degrees());
-        assert_eq!(wgs.altitude, nav_type.altitude());
+        assert_eq!(wgs.altitude, Some(nav_type.altitude()));
     }
 
     #[quickcheck]
-    fn roundtrip_test(north: f64, east: f64, altitude: f64) -> () {
+    fn roundtrip_test(north: f64, east: f64, altitude: f64) {
         let lv03 = Lv03::new(north, east, altitude);
         //let lv03 = Lv03::new(f64::NAN, f64::NEG_INFINITY, f64::INFINITY);
         if let Some(lv03) = lv03 {
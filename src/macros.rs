@@ -0,0 +1,33 @@
+/// Creates an `Lv03` point with named axes to prevent swapping them, e.g.
+/// `lv03!(east = 600_000, north = 200_000, alt = 540)`.
+/// Without `alt` the point has no altitude.
+///
+/// Panics if the coordinates are not valid, so this is meant for tests and fixtures.
+///
+/// ```
+/// use lv03::{lv03, Lv03};
+///
+/// let p = lv03!(east = 600_421.43, north = 199_498.43, alt = 542.8);
+/// assert_eq!(Lv03::new(199_498.43, 600_421.43, 542.8), Some(p));
+/// ```
+#[macro_export]
+macro_rules! lv03 {
+    (east = $east:expr, north = $north:expr, alt = $alt:expr $(,)?) => {{
+        let (east, north) = ($east as f64, $north as f64);
+        $crate::Lv03::new(north, east, $alt as f64).unwrap_or_else(|| {
+            panic!(
+                "invalid LV03 coordinates: east = {}, north = {}",
+                east, north
+            )
+        })
+    }};
+    (east = $east:expr, north = $north:expr $(,)?) => {{
+        let (east, north) = ($east as f64, $north as f64);
+        $crate::Lv03::new_2d(north, east).unwrap_or_else(|| {
+            panic!(
+                "invalid LV03 coordinates: east = {}, north = {}",
+                east, north
+            )
+        })
+    }};
+}
@@ -0,0 +1,180 @@
+use crate::Wgs84;
+
+impl Wgs84 {
+    /// Parses a NMEA GGA sentence (e.g. `$GPGGA` or `$GNGGA`) as sent by most GPS receivers.
+    /// The checksum is validated if present.
+    ///
+    /// The altitude is the sum of the height above mean sea level and the geoid separation,
+    /// i.e. the height above the WGS84 ellipsoid. If the geoid separation is missing the
+    /// height above mean sea level is used. Returns none for malformed sentences and
+    /// sentences without a position fix.
+    pub fn from_nmea_gga(sentence: &str) -> Option<Wgs84> {
+        let sentence = sentence.trim_end().strip_prefix('$')?;
+        let data = match sentence.split_once('*') {
+            Some((data, checksum)) => {
+                let checksum = u8::from_str_radix(checksum, 16).ok()?;
+                if data.bytes().fold(0, |acc, b| acc ^ b) != checksum {
+                    return None;
+                }
+                data
+            }
+            None => sentence,
+        };
+
+        let mut fields = data.split(',');
+        let sentence_type = fields.next()?;
+        if sentence_type.len() != 5 || !sentence_type.ends_with("GGA") {
+            return None;
+        }
+        let _time = fields.next()?;
+        let latitude = parse_nmea_angle(fields.next()?, fields.next()?, 90.0, 'N', 'S')?;
+        let longitude = parse_nmea_angle(fields.next()?, fields.next()?, 180.0, 'E', 'W')?;
+        let fix_quality: u8 = fields.next()?.parse().ok()?;
+        if fix_quality == 0 {
+            return None;
+        }
+        let _satellites = fields.next()?;
+        let _hdop = fields.next()?;
+        let altitude = parse_optional(fields.next()?)?;
+        let _altitude_unit = fields.next()?;
+        let geoid_separation = parse_optional(fields.next().unwrap_or(""))?;
+        let altitude = altitude.map(|altitude| altitude + geoid_separation.unwrap_or(0.0));
+
+        Some(Wgs84 {
+            longitude,
+            latitude,
+            altitude,
+        })
+    }
+}
+
+/// Parses an empty field as `Some(None)` and a malformed one as `None`
+fn parse_optional(field: &str) -> Option<Option<f64>> {
+    if field.is_empty() {
+        Some(None)
+    } else {
+        field.parse().ok().map(Some)
+    }
+}
+
+/// Converts the NMEA `dddmm.mmmm` notation with its hemisphere into decimal degrees.
+/// Returns none if the minutes are not below 60 or the angle exceeds `max_degrees`
+fn parse_nmea_angle(
+    value: &str,
+    hemisphere: &str,
+    max_degrees: f64,
+    positive: char,
+    negative: char,
+) -> Option<f64> {
+    let value: f64 = value.parse().ok()?;
+    if value.is_nan() || value < 0.0 {
+        return None;
+    }
+    let degrees = (value / 100.0) as u32 as f64;
+    let minutes = value - 100.0 * degrees;
+    let angle = degrees + minutes / 60.0;
+    if minutes >= 60.0 || angle > max_degrees {
+        return None;
+    }
+    match hemisphere.chars().next() {
+        Some(c) if c == positive => Some(angle),
+        Some(c) if c == negative => Some(-angle),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gga() {
+        let wgs = Wgs84::from_nmea_gga(
+            "$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47",
+        )
+        .unwrap();
+        assert!((wgs.latitude - 48.1173).abs() < 1e-9);
+        assert!((wgs.longitude - 11.516_666_666).abs() < 1e-6);
+        assert!((wgs.altitude.unwrap() - 592.3).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_gga_to_lv03() {
+        let sentence = "$GNGGA,101010.00,4656.7948,N,00726.6502,E,1,12,0.8,541.2,M,48.2,M,,*75\r\n";
+        let lv03 = Wgs84::from_nmea_gga(sentence).unwrap().to_lv03().unwrap();
+        assert!((lv03.east - 600_421.43).abs() < 5.0);
+        assert!((lv03.north - 199_498.43).abs() < 5.0);
+    }
+
+    #[test]
+    fn test_gga_without_checksum_or_altitude() {
+        let wgs =
+            Wgs84::from_nmea_gga("$GPGGA,123519,4807.038,S,01131.000,W,1,08,0.9,,M,,M,,").unwrap();
+        assert!((wgs.latitude + 48.1173).abs() < 1e-9);
+        assert!((wgs.longitude + 11.516_666_666).abs() < 1e-6);
+        assert_eq!(None, wgs.altitude);
+    }
+
+    #[test]
+    fn test_gga_invalid() {
+        // Wrong checksum
+        assert!(Wgs84::from_nmea_gga(
+            "$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*48"
+        )
+        .is_none());
+        // No fix
+        assert!(Wgs84::from_nmea_gga(
+            "$GPGGA,123519,4807.038,N,01131.000,E,0,08,0.9,545.4,M,46.9,M,,"
+        )
+        .is_none());
+        // Other sentence type
+        assert!(Wgs84::from_nmea_gga(
+            "$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W"
+        )
+        .is_none());
+        // Truncated
+        assert!(Wgs84::from_nmea_gga("$GPGGA,123519,4807.038,N").is_none());
+        assert!(Wgs84::from_nmea_gga(
+            "GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,"
+        )
+        .is_none());
+        assert!(Wgs84::from_nmea_gga("").is_none());
+    }
+
+    #[test]
+    fn test_parse_nmea_angle() {
+        assert_eq!(Some(48.5), parse_nmea_angle("4830.0", "N", 90.0, 'N', 'S'));
+        assert_eq!(Some(-90.0), parse_nmea_angle("9000.0", "S", 90.0, 'N', 'S'));
+        assert_eq!(
+            Some(180.0),
+            parse_nmea_angle("18000.0", "E", 180.0, 'E', 'W')
+        );
+        // Minutes of 60 or more
+        assert_eq!(None, parse_nmea_angle("4875.0", "N", 90.0, 'N', 'S'));
+        assert_eq!(None, parse_nmea_angle("4860.0", "N", 90.0, 'N', 'S'));
+        // Beyond the range of the axis
+        assert_eq!(None, parse_nmea_angle("9000.1", "N", 90.0, 'N', 'S'));
+        assert_eq!(None, parse_nmea_angle("12000.0", "N", 90.0, 'N', 'S'));
+        assert_eq!(
+            Some(120.0),
+            parse_nmea_angle("12000.0", "E", 180.0, 'E', 'W')
+        );
+        assert_eq!(None, parse_nmea_angle("18000.1", "E", 180.0, 'E', 'W'));
+        assert_eq!(None, parse_nmea_angle("-4830.0", "N", 90.0, 'N', 'S'));
+        assert_eq!(None, parse_nmea_angle("NaN", "N", 90.0, 'N', 'S'));
+    }
+
+    #[test]
+    fn test_gga_out_of_range() {
+        // Latitude of 120 degrees
+        assert!(Wgs84::from_nmea_gga(
+            "$GPGGA,123519,12007.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,"
+        )
+        .is_none());
+        // 75 minutes
+        assert!(Wgs84::from_nmea_gga(
+            "$GPGGA,123519,4875.000,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,"
+        )
+        .is_none());
+    }
+}
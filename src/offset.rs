@@ -0,0 +1,71 @@
+use crate::Lv03;
+use core::ops::Add;
+
+/// Displacement between two LV03 points in meters, see `Lv03::vector_to`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Lv03Offset {
+    /// Displacement towards north
+    pub d_north: f64,
+    /// Displacement towards east
+    pub d_east: f64,
+    /// Change of altitude, `None` if not both points have a known altitude
+    pub d_altitude: Option<f64>,
+}
+
+impl Lv03 {
+    /// Displacement from this point to the other, such that `self + self.vector_to(other)`
+    /// gives `other` again if both or none of the points have an altitude
+    pub fn vector_to(&self, other: &Lv03) -> Lv03Offset {
+        Lv03Offset {
+            d_north: other.north - self.north,
+            d_east: other.east - self.east,
+            d_altitude: self
+                .altitude
+                .zip(other.altitude)
+                .map(|(from, to)| to - from),
+        }
+    }
+}
+
+impl Add<Lv03Offset> for Lv03 {
+    type Output = Lv03;
+
+    /// Moves the point by the offset. The result is not checked against the bounds of the
+    /// swiss coordinate system. An offset without altitude change keeps the altitude
+    fn add(self, offset: Lv03Offset) -> Lv03 {
+        Lv03 {
+            north: self.north + offset.d_north,
+            east: self.east + offset.d_east,
+            altitude: match (self.altitude, offset.d_altitude) {
+                (Some(altitude), Some(d_altitude)) => Some(altitude + d_altitude),
+                (altitude, _) => altitude,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vector_to() {
+        let a = Lv03::new(199_498.43, 600_421.43, 542.8).unwrap();
+        let b = Lv03::new(91_116.9, 617_049.7, 4_478.0).unwrap();
+        let offset = a.vector_to(&b);
+        assert_eq!(b.north - a.north, offset.d_north);
+        assert_eq!(b.east - a.east, offset.d_east);
+        let c = a.clone() + offset;
+        assert!(c.distance_squared(&b) < 1e-12);
+        assert!((c.altitude.unwrap() - b.altitude.unwrap()).abs() < 1e-9);
+
+        let a_2d = Lv03::new_2d(199_498.43, 600_421.43).unwrap();
+        let b_2d = Lv03::new_2d(91_116.9, 617_049.7).unwrap();
+        let offset = a_2d.vector_to(&b_2d);
+        assert_eq!(None, offset.d_altitude);
+        assert!((a_2d + offset).distance_squared(&b_2d) < 1e-12);
+
+        // Offset without altitude change
+        assert_eq!(Some(542.8), (a.clone() + a.vector_to(&b_2d)).altitude);
+    }
+}
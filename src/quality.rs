@@ -0,0 +1,55 @@
+//! Rough classification of the accuracy of the conversion for display to end users
+
+use crate::Lv03;
+
+/// Origin of the coordinate system in Bern as `(north, east)`, where the approximation formulas
+/// are developed and most accurate
+const ORIGIN: (f64, f64) = (200_000.0, 600_000.0);
+
+/// Accuracy of the conversion between LV03 and WGS84, see `Lv03::conversion_quality`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum QualityTier {
+    /// Less than 100 km from the origin in Bern
+    Excellent,
+    /// 100 km to 200 km from the origin
+    Good,
+    /// 200 km or more from the origin, e.g. towards the corners of the domain
+    Degraded,
+}
+
+impl Lv03 {
+    /// Accuracy of the conversion to WGS84 classified by the horizontal distance from the
+    /// origin of the coordinate system in Bern, as the error of the approximation formulas
+    /// grows with it. Meant for a simple indication to end users, use `roundtrip_error` to
+    /// check the accuracy at a point
+    pub fn conversion_quality(&self) -> QualityTier {
+        let d_north = self.north - ORIGIN.0;
+        let d_east = self.east - ORIGIN.1;
+        let distance_squared = d_north * d_north + d_east * d_east;
+        if distance_squared < 100_000.0 * 100_000.0 {
+            QualityTier::Excellent
+        } else if distance_squared < 200_000.0 * 200_000.0 {
+            QualityTier::Good
+        } else {
+            QualityTier::Degraded
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversion_quality() {
+        let origin = Lv03::new_2d(200_000.0, 600_000.0).unwrap();
+        assert_eq!(QualityTier::Excellent, origin.conversion_quality());
+        let bundeshaus = Lv03::new(199_498.43, 600_421.43, 542.8).unwrap();
+        assert_eq!(QualityTier::Excellent, bundeshaus.conversion_quality());
+        let matterhorn = Lv03::new(91_673.72, 617_049.89, 4477.4).unwrap();
+        assert_eq!(QualityTier::Good, matterhorn.conversion_quality());
+        let corner = Lv03::new_2d(70_000.0, 850_000.0).unwrap();
+        assert_eq!(QualityTier::Degraded, corner.conversion_quality());
+        assert!(QualityTier::Excellent < QualityTier::Degraded);
+    }
+}
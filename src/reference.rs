@@ -0,0 +1,72 @@
+//! Reference points for tests of downstream crates.
+//!
+//! The WGS84 values agree with the LV03 and LV95 values within the accuracy of the
+//! approximation formulas used by this crate: about a meter horizontally and a few meters in
+//! altitude. LV03 altitudes are heights above sea level, WGS84 altitudes are ellipsoidal
+//! heights. Compare converted values with a tolerance rather than for equality
+
+use crate::{Lv03, Lv95, Wgs84};
+
+/// Federal palace (Bundeshaus) in Bern, close to the origin of the swiss coordinate system
+pub const BUNDESHAUS_LV03: Lv03 = Lv03 {
+    north: 199_498.43,
+    east: 600_421.43,
+    altitude: Some(542.8),
+};
+
+/// Federal palace in LV95, the same point as `BUNDESHAUS_LV03`
+pub const BUNDESHAUS_LV95: Lv95 = Lv95 {
+    north: 1_199_498.43,
+    east: 2_600_421.43,
+    altitude: Some(542.8),
+};
+
+/// Federal palace in WGS84, rounded to 5 decimal places
+pub const BUNDESHAUS_WGS84: Wgs84 = Wgs84 {
+    longitude: 7.44417,
+    latitude: 46.94658,
+    altitude: Some(591.8),
+};
+
+/// Summit of the Matterhorn, near the southern border
+pub const MATTERHORN_LV03: Lv03 = Lv03 {
+    north: 91_673.72,
+    east: 617_049.89,
+    altitude: Some(4477.4),
+};
+
+/// Summit of the Matterhorn in LV95, the same point as `MATTERHORN_LV03`
+pub const MATTERHORN_LV95: Lv95 = Lv95 {
+    north: 1_091_673.72,
+    east: 2_617_049.89,
+    altitude: Some(4477.4),
+};
+
+/// Summit of the Matterhorn in WGS84, rounded to 5 decimal places
+pub const MATTERHORN_WGS84: Wgs84 = Wgs84 {
+    longitude: 7.65861,
+    latitude: 45.97642,
+    altitude: Some(4532.9),
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(lv03: &Lv03, lv95: &Lv95, wgs: &Wgs84) {
+        assert!(Lv03::from(lv95.clone()).distance_squared(lv03) < 1e-12);
+        let converted = lv03.to_wgs84();
+        assert!((converted.longitude - wgs.longitude).abs() < 2e-5);
+        assert!((converted.latitude - wgs.latitude).abs() < 2e-5);
+        assert!((converted.altitude.unwrap() - wgs.altitude.unwrap()).abs() < 5.0);
+        let converted = wgs.to_lv03().unwrap();
+        assert!((converted.east - lv03.east).abs() < 2.0);
+        assert!((converted.north - lv03.north).abs() < 2.0);
+    }
+
+    #[test]
+    fn test_reference_points() {
+        check(&BUNDESHAUS_LV03, &BUNDESHAUS_LV95, &BUNDESHAUS_WGS84);
+        check(&MATTERHORN_LV03, &MATTERHORN_LV95, &MATTERHORN_WGS84);
+    }
+}
@@ -0,0 +1,149 @@
+//! Single entry point for conversions between reference frames, following the frame pairs of
+//! the swisstopo REFRAME service
+
+use crate::{Lv03, Lv95, Wgs84};
+
+/// Reference frame including its height system
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Frame {
+    /// WGS84 with ellipsoidal heights
+    Wgs84,
+    /// LV03 with heights in the levelling network LN02
+    Lv03Ln02,
+    /// LV95 with heights in the national height network LHN95
+    Lv95Lhn95,
+}
+
+/// Coordinate in one of the supported frames
+#[derive(Clone, Debug, PartialEq)]
+pub enum Coordinate {
+    Wgs84(Wgs84),
+    Lv03(Lv03),
+    Lv95(Lv95),
+}
+
+impl Coordinate {
+    /// Whether the coordinate is of the type used by the frame
+    fn is_in(&self, frame: Frame) -> bool {
+        matches!(
+            (self, frame),
+            (Coordinate::Wgs84(_), Frame::Wgs84)
+                | (Coordinate::Lv03(_), Frame::Lv03Ln02)
+                | (Coordinate::Lv95(_), Frame::Lv95Lhn95)
+        )
+    }
+}
+
+/// Converts the coordinate from one frame to another. All pairs of frames are supported,
+/// converting to the same frame returns the input unchanged.
+///
+/// Returns none if the coordinate does not match the `from` frame or if it lies outside of the
+/// swiss coordinate system when converting from WGS84.
+///
+/// Heights between WGS84 and the swiss frames are converted with the approximation formulas,
+/// accurate to a few meters. Unlike the REFRAME service no correction between LN02 and LHN95
+/// is applied, the two differ by up to half a meter within Switzerland
+pub fn reframe(input: Coordinate, from: Frame, to: Frame) -> Option<Coordinate> {
+    if !input.is_in(from) {
+        return None;
+    }
+    let converted = match (input, to) {
+        (input, to) if from == to => input,
+        (Coordinate::Wgs84(p), Frame::Lv03Ln02) => Coordinate::Lv03(p.to_lv03()?),
+        (Coordinate::Wgs84(p), Frame::Lv95Lhn95) => Coordinate::Lv95(p.to_lv95()?),
+        (Coordinate::Lv03(p), Frame::Wgs84) => Coordinate::Wgs84(p.to_wgs84()),
+        (Coordinate::Lv03(p), Frame::Lv95Lhn95) => Coordinate::Lv95(p.into()),
+        (Coordinate::Lv95(p), Frame::Wgs84) => Coordinate::Wgs84(p.to_wgs84()),
+        (Coordinate::Lv95(p), Frame::Lv03Ln02) => Coordinate::Lv03(p.into()),
+        // Covered by the checks above
+        _ => return None,
+    };
+    Some(converted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reframe() {
+        let lv03 = Lv03::new(199_498.43, 600_421.43, 542.8).unwrap();
+        let wgs = lv03.to_wgs84();
+        let lv95: Lv95 = lv03.clone().into();
+
+        assert_eq!(
+            Some(Coordinate::Wgs84(wgs.clone())),
+            reframe(
+                Coordinate::Lv03(lv03.clone()),
+                Frame::Lv03Ln02,
+                Frame::Wgs84
+            )
+        );
+        assert_eq!(
+            Some(Coordinate::Lv95(lv95.clone())),
+            reframe(
+                Coordinate::Lv03(lv03.clone()),
+                Frame::Lv03Ln02,
+                Frame::Lv95Lhn95
+            )
+        );
+        match reframe(
+            Coordinate::Lv95(lv95.clone()),
+            Frame::Lv95Lhn95,
+            Frame::Lv03Ln02,
+        ) {
+            Some(Coordinate::Lv03(p)) => assert!(p.distance_squared(&lv03) < 1e-12),
+            other => panic!("unexpected {:?}", other),
+        }
+        assert_eq!(
+            Some(Coordinate::Wgs84(lv95.to_wgs84())),
+            reframe(
+                Coordinate::Lv95(lv95.clone()),
+                Frame::Lv95Lhn95,
+                Frame::Wgs84
+            )
+        );
+        assert_eq!(
+            Some(Coordinate::Lv03(wgs.to_lv03().unwrap())),
+            reframe(
+                Coordinate::Wgs84(wgs.clone()),
+                Frame::Wgs84,
+                Frame::Lv03Ln02
+            )
+        );
+        assert_eq!(
+            Some(Coordinate::Lv95(wgs.to_lv95().unwrap())),
+            reframe(
+                Coordinate::Wgs84(wgs.clone()),
+                Frame::Wgs84,
+                Frame::Lv95Lhn95
+            )
+        );
+        assert_eq!(
+            Some(Coordinate::Lv03(lv03.clone())),
+            reframe(
+                Coordinate::Lv03(lv03.clone()),
+                Frame::Lv03Ln02,
+                Frame::Lv03Ln02
+            )
+        );
+    }
+
+    #[test]
+    fn test_reframe_invalid() {
+        let lv03 = Lv03::new(199_498.43, 600_421.43, 542.8).unwrap();
+        assert_eq!(
+            None,
+            reframe(Coordinate::Lv03(lv03), Frame::Lv95Lhn95, Frame::Wgs84)
+        );
+        let paris = Wgs84 {
+            longitude: 2.35,
+            latitude: 48.86,
+            altitude: None,
+        };
+        assert_eq!(
+            None,
+            reframe(Coordinate::Wgs84(paris), Frame::Wgs84, Frame::Lv95Lhn95)
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{Bounds, Lv03};
+use core::fmt;
+
+/// Coarse natural region of Switzerland as used on tourist maps, see `Lv03::region`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Region {
+    Jura,
+    Mittelland,
+    Alps,
+    Ticino,
+}
+
+impl Lv03 {
+    /// Coarse region of Switzerland containing the point, none outside of the swiss
+    /// coordinate system. The regions are separated by straight lines and are only
+    /// approximate, points within ten to twenty kilometers of a border between two
+    /// regions may be assigned to either. Points outside of Switzerland but within the
+    /// swiss coordinate system are assigned to the closest region
+    pub fn region(&self) -> Option<Region> {
+        if !Bounds::SWITZERLAND.contains(self.north, self.east) {
+            return None;
+        }
+        let region = if (670_000.0..=740_000.0).contains(&self.east) && self.north < 155_000.0 {
+            Region::Ticino
+        } else if self.north < 143_000.0 + 0.5 * (self.east - 560_000.0) {
+            // Northern edge of the Prealps from Montreux towards Lake Constance
+            Region::Alps
+        } else if self.east < 670_000.0 && self.north > 118_000.0 + 0.898 * (self.east - 500_000.0)
+        {
+            // Southern foot of the Jura from Geneva towards Brugg
+            Region::Jura
+        } else {
+            Region::Mittelland
+        };
+        Some(region)
+    }
+}
+
+impl fmt::Display for Region {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Region::Jura => "Jura",
+            Region::Mittelland => "Mittelland",
+            Region::Alps => "Alps",
+            Region::Ticino => "Ticino",
+        };
+        f.write_str(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_region() {
+        let region = |east, north| Lv03::new_2d(north, east).unwrap().region();
+        // Bern
+        assert_eq!(Some(Region::Mittelland), region(600_000.0, 200_000.0));
+        // Zurich
+        assert_eq!(Some(Region::Mittelland), region(683_000.0, 248_000.0));
+        // Lausanne
+        assert_eq!(Some(Region::Mittelland), region(538_000.0, 152_000.0));
+        // La Chaux-de-Fonds
+        assert_eq!(Some(Region::Jura), region(553_000.0, 217_000.0));
+        // Matterhorn
+        assert_eq!(Some(Region::Alps), region(617_049.89, 91_673.72));
+        // Chur
+        assert_eq!(Some(Region::Alps), region(759_000.0, 191_000.0));
+        // Lugano
+        assert_eq!(Some(Region::Ticino), region(717_000.0, 96_000.0));
+
+        let outside = Lv03 {
+            north: 50_000.0,
+            east: 600_000.0,
+            altitude: None,
+        };
+        assert_eq!(None, outside.region());
+    }
+}
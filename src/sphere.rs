@@ -0,0 +1,337 @@
+//! Great circle computations for WGS84 coordinates on a spherical earth model.
+//! They also work outside of Switzerland where the swiss grid is not valid
+
+use crate::Wgs84;
+use core::f64::consts::PI;
+
+/// Mean earth radius in meters
+const EARTH_RADIUS: f64 = 6_371_000.0;
+
+/// Wraps a longitude in degrees into -180..180
+fn normalize_longitude(longitude: f64) -> f64 {
+    let wrapped = (longitude + 180.0) % 360.0;
+    if wrapped < 0.0 {
+        wrapped + 180.0
+    } else {
+        wrapped - 180.0
+    }
+}
+
+impl Wgs84 {
+    /// Point reached when travelling `distance_m` meters along a great circle with the given
+    /// initial bearing (degrees clockwise from north). The altitude is carried over unchanged
+    pub fn destination(&self, bearing_deg: f64, distance_m: f64) -> Wgs84 {
+        let phi = self.latitude_rad();
+        let lambda = self.longitude_rad();
+        let theta = bearing_deg.to_radians();
+        let delta = distance_m / EARTH_RADIUS;
+
+        let sin_phi_2 = libm::sin(phi) * libm::cos(delta)
+            + libm::cos(phi) * libm::sin(delta) * libm::cos(theta);
+        let phi_2 = libm::asin(sin_phi_2);
+        let lambda_2 = lambda
+            + libm::atan2(
+                libm::sin(theta) * libm::sin(delta) * libm::cos(phi),
+                libm::cos(delta) - libm::sin(phi) * sin_phi_2,
+            );
+        Wgs84 {
+            longitude: normalize_longitude(lambda_2.to_degrees()),
+            latitude: phi_2.to_degrees(),
+            altitude: self.altitude,
+        }
+    }
+
+    /// Great circle (orthodromic) distance in meters to the other position, using the haversine
+    /// formula. The altitude is ignored
+    pub fn haversine_distance(&self, other: &Wgs84) -> f64 {
+        let (phi_1, phi_2) = (self.latitude_rad(), other.latitude_rad());
+        let sin_d_phi = libm::sin((phi_2 - phi_1) / 2.0);
+        let sin_d_lambda = libm::sin((other.longitude_rad() - self.longitude_rad()) / 2.0);
+        let h = sin_d_phi * sin_d_phi
+            + libm::cos(phi_1) * libm::cos(phi_2) * sin_d_lambda * sin_d_lambda;
+        2.0 * EARTH_RADIUS * libm::asin(libm::sqrt(h).min(1.0))
+    }
+
+    /// Rhumb line (loxodromic) distance in meters to the other position, i.e. the length of the
+    /// path with a constant bearing. It is never shorter than the `haversine_distance`, within
+    /// Switzerland the two differ by a few centimeters only. The altitude is ignored
+    pub fn rhumb_distance(&self, other: &Wgs84) -> f64 {
+        let (phi_1, phi_2) = (self.latitude_rad(), other.latitude_rad());
+        let d_phi = phi_2 - phi_1;
+        let d_lambda = normalize_longitude(other.longitude - self.longitude).to_radians();
+        // Difference of the inverse Gudermannian, i.e. of the Mercator projected latitudes
+        let d_psi =
+            libm::log(libm::tan(PI / 4.0 + phi_2 / 2.0) / libm::tan(PI / 4.0 + phi_1 / 2.0));
+        // Along a parallel the ratio degenerates to the cosine of the latitude
+        let q = if d_psi.abs() > 1e-12 {
+            d_phi / d_psi
+        } else {
+            libm::cos(phi_1)
+        };
+        EARTH_RADIUS * libm::sqrt(d_phi * d_phi + q * q * d_lambda * d_lambda)
+    }
+
+    /// Point halfway along the great circle to the other position, which differs from the mean
+    /// of the latitudes and longitudes over long distances. The altitude is averaged if both
+    /// positions have one
+    pub fn midpoint(&self, other: &Wgs84) -> Wgs84 {
+        let (phi_1, phi_2) = (self.latitude_rad(), other.latitude_rad());
+        let d_lambda = other.longitude_rad() - self.longitude_rad();
+        let b_x = libm::cos(phi_2) * libm::cos(d_lambda);
+        let b_y = libm::cos(phi_2) * libm::sin(d_lambda);
+        let cos_phi_1 = libm::cos(phi_1) + b_x;
+        let phi = libm::atan2(
+            libm::sin(phi_1) + libm::sin(phi_2),
+            libm::sqrt(cos_phi_1 * cos_phi_1 + b_y * b_y),
+        );
+        let lambda = self.longitude_rad() + libm::atan2(b_y, cos_phi_1);
+        let altitude = match (self.altitude, other.altitude) {
+            (Some(a), Some(b)) => Some((a + b) / 2.0),
+            _ => None,
+        };
+        Wgs84 {
+            longitude: normalize_longitude(lambda.to_degrees()),
+            latitude: phi.to_degrees(),
+            altitude,
+        }
+    }
+
+    /// Initial bearing (forward azimuth) in degrees (0..360) clockwise from true north of the
+    /// great circle from this position to the other. It changes along the way, unlike the
+    /// grid bearing `Lv03::bearing_to`
+    pub fn initial_bearing(&self, other: &Wgs84) -> f64 {
+        let (phi_1, phi_2) = (self.latitude_rad(), other.latitude_rad());
+        let d_lambda = other.longitude_rad() - self.longitude_rad();
+        let y = libm::sin(d_lambda) * libm::cos(phi_2);
+        let x = libm::cos(phi_1) * libm::sin(phi_2)
+            - libm::sin(phi_1) * libm::cos(phi_2) * libm::cos(d_lambda);
+        crate::normalize_bearing(libm::atan2(y, x).to_degrees())
+    }
+}
+
+/// Area in square meters enclosed by the polygon on the spherical earth, using the
+/// formula of Chamberlain and Duquette. The polygon is closed implicitly and may be given in
+/// either orientation, the altitude is ignored. Returns 0 for fewer than three points.
+///
+/// For regions within Switzerland the planar `polygon_area` on the LV03 grid is more accurate,
+/// as the sphere deviates from the ellipsoid by a few tenths of a percent
+pub fn wgs84_polygon_area(points: &[Wgs84]) -> f64 {
+    let n = points.len();
+    if n < 3 {
+        return 0.0;
+    }
+    let sum: f64 = (0..n)
+        .map(|i| {
+            let previous = &points[(i + n - 1) % n];
+            let next = &points[(i + 1) % n];
+            let d_lambda = normalize_longitude(next.longitude - previous.longitude).to_radians();
+            d_lambda * libm::sin(points[i].latitude_rad())
+        })
+        .sum();
+    (sum * EARTH_RADIUS * EARTH_RADIUS / 2.0).abs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ORIGIN: Wgs84 = Wgs84 {
+        longitude: 0.0,
+        latitude: 0.0,
+        altitude: Some(100.0),
+    };
+
+    #[test]
+    fn test_haversine_and_rhumb_distance() {
+        let new_york = Wgs84 {
+            longitude: -74.006,
+            latitude: 40.7128,
+            altitude: None,
+        };
+        let london = Wgs84 {
+            longitude: -0.1278,
+            latitude: 51.5074,
+            altitude: None,
+        };
+        assert!((new_york.haversine_distance(&london) - 5_570_222.0).abs() < 1.0);
+        assert!((new_york.rhumb_distance(&london) - 5_794_121.0).abs() < 1.0);
+        assert!((london.rhumb_distance(&new_york) - 5_794_121.0).abs() < 1.0);
+
+        // Along a parallel the rhumb line follows the parallel
+        let baghdad = Wgs84 {
+            longitude: 45.0,
+            latitude: 35.0,
+            altitude: None,
+        };
+        let osaka = Wgs84 {
+            longitude: 135.0,
+            latitude: 35.0,
+            altitude: None,
+        };
+        let parallel = EARTH_RADIUS * PI / 2.0 * 35f64.to_radians().cos();
+        assert!((baghdad.rhumb_distance(&osaka) - parallel).abs() < 1e-6);
+        assert!((baghdad.haversine_distance(&osaka) - 7_871_769.0).abs() < 1.0);
+
+        // Across the antimeridian
+        let east = Wgs84 {
+            longitude: 179.0,
+            latitude: 0.0,
+            altitude: None,
+        };
+        let west = Wgs84 {
+            longitude: -179.0,
+            latitude: 0.0,
+            altitude: None,
+        };
+        let two_degrees = EARTH_RADIUS * 2f64.to_radians();
+        assert!((east.rhumb_distance(&west) - two_degrees).abs() < 1e-6);
+        assert!((east.haversine_distance(&west) - two_degrees).abs() < 1e-6);
+
+        let bundeshaus = Wgs84 {
+            longitude: 7.44417,
+            latitude: 46.94658,
+            altitude: None,
+        };
+        assert_eq!(0.0, bundeshaus.haversine_distance(&bundeshaus));
+        assert_eq!(0.0, bundeshaus.rhumb_distance(&bundeshaus));
+    }
+
+    #[test]
+    fn test_midpoint() {
+        let new_york = Wgs84 {
+            longitude: -74.006,
+            latitude: 40.7128,
+            altitude: Some(10.0),
+        };
+        let london = Wgs84 {
+            longitude: -0.1278,
+            latitude: 51.5074,
+            altitude: Some(20.0),
+        };
+        let midpoint = new_york.midpoint(&london);
+        // North of both latitudes, unlike the mean of the coordinates
+        assert!((midpoint.latitude - 52.3684).abs() < 1e-4);
+        assert!((midpoint.longitude + 41.2903).abs() < 1e-4);
+        assert_eq!(Some(15.0), midpoint.altitude);
+        let half = new_york.haversine_distance(&london) / 2.0;
+        assert!((new_york.haversine_distance(&midpoint) - half).abs() < 1e-3);
+        assert!((london.haversine_distance(&midpoint) - half).abs() < 1e-3);
+
+        let east = Wgs84 {
+            longitude: 170.0,
+            latitude: 10.0,
+            altitude: None,
+        };
+        let west = Wgs84 {
+            longitude: -170.0,
+            latitude: 10.0,
+            altitude: Some(20.0),
+        };
+        let midpoint = east.midpoint(&west);
+        assert!((midpoint.longitude.abs() - 180.0).abs() < 1e-9);
+        assert_eq!(None, midpoint.altitude);
+    }
+
+    #[test]
+    fn test_destination() {
+        let east = ORIGIN.destination(90.0, EARTH_RADIUS * PI / 2.0);
+        assert!(east.latitude.abs() < 1e-9);
+        assert!((east.longitude - 90.0).abs() < 1e-9);
+        assert_eq!(Some(100.0), east.altitude);
+
+        let north = ORIGIN.destination(0.0, EARTH_RADIUS * PI / 4.0);
+        assert!((north.latitude - 45.0).abs() < 1e-9);
+        assert!(north.longitude.abs() < 1e-9);
+
+        let west = ORIGIN.destination(270.0, EARTH_RADIUS * PI * 3.0 / 4.0);
+        assert!((west.longitude + 135.0).abs() < 1e-9);
+
+        // Beyond the antimeridian
+        let start = Wgs84 {
+            longitude: 170.0,
+            latitude: 0.0,
+            altitude: None,
+        };
+        let p = start.destination(90.0, EARTH_RADIUS * 20f64.to_radians());
+        assert!((p.longitude + 170.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_destination_bern() {
+        let bern = Wgs84 {
+            longitude: 7.43863,
+            latitude: 46.95108,
+            altitude: None,
+        };
+        // 1 degree of latitude along the meridian
+        let p = bern.destination(0.0, EARTH_RADIUS * 1f64.to_radians());
+        assert!((p.latitude - 47.95108).abs() < 1e-9);
+        assert!((p.longitude - 7.43863).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_initial_bearing() {
+        let point = |longitude, latitude| Wgs84 {
+            longitude,
+            latitude,
+            altitude: None,
+        };
+        assert!((ORIGIN.initial_bearing(&point(10.0, 0.0)) - 90.0).abs() < 1e-9);
+        assert!((ORIGIN.initial_bearing(&point(-10.0, 0.0)) - 270.0).abs() < 1e-9);
+        assert!(ORIGIN.initial_bearing(&point(0.0, 10.0)).abs() < 1e-9);
+        assert!((ORIGIN.initial_bearing(&point(0.0, -10.0)) - 180.0).abs() < 1e-9);
+
+        // Consistent with destination
+        let bern = point(7.43863, 46.95108);
+        let target = bern.destination(60.0, 100_000.0);
+        assert!((bern.initial_bearing(&target) - 60.0).abs() < 1e-9);
+
+        // Heading east at 45 degrees north, the great circle starts north of east
+        let bearing = point(0.0, 45.0).initial_bearing(&point(90.0, 45.0));
+        assert!((bearing - 54.74).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_wgs84_polygon_area() {
+        let point = |longitude, latitude| Wgs84 {
+            longitude,
+            latitude,
+            altitude: None,
+        };
+        // Quarter of the zone between the equator and 30 degrees north
+        let zone = [
+            point(0.0, 0.0),
+            point(90.0, 0.0),
+            point(90.0, 30.0),
+            point(0.0, 30.0),
+        ];
+        let expected = PI * EARTH_RADIUS * EARTH_RADIUS / 4.0;
+        assert!((wgs84_polygon_area(&zone) - expected).abs() / expected < 1e-12);
+        let reversed = [
+            zone[3].clone(),
+            zone[2].clone(),
+            zone[1].clone(),
+            zone[0].clone(),
+        ];
+        assert!((wgs84_polygon_area(&reversed) - expected).abs() / expected < 1e-12);
+
+        // Across the antimeridian
+        let zone = [
+            point(150.0, 0.0),
+            point(-120.0, 0.0),
+            point(-120.0, 30.0),
+            point(150.0, 30.0),
+        ];
+        assert!((wgs84_polygon_area(&zone) - expected).abs() / expected < 1e-12);
+
+        assert_eq!(0.0, wgs84_polygon_area(&zone[..2]));
+    }
+
+    #[test]
+    fn test_normalize_longitude() {
+        assert_eq!(0.0, normalize_longitude(0.0));
+        assert_eq!(-170.0, normalize_longitude(190.0));
+        assert_eq!(170.0, normalize_longitude(-190.0));
+        assert_eq!(-180.0, normalize_longitude(180.0));
+    }
+}
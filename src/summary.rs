@@ -0,0 +1,109 @@
+use crate::{Lv03, Lv03BoundingBox};
+#[cfg(feature = "alloc")]
+use alloc::collections::BTreeMap;
+
+/// Aggregate information about a set of points, see `summarize`
+#[derive(Clone, Debug, PartialEq)]
+pub struct PointSummary {
+    /// Smallest box containing all points
+    pub bounds: Lv03BoundingBox,
+    /// Mean of all points. The altitude is the mean of the points with a known altitude
+    pub centroid: Lv03,
+    /// Number of points
+    pub count: usize,
+}
+
+/// Computes bounding box and centroid in a single pass over the points.
+/// Returns none for an empty slice
+pub fn summarize(points: &[Lv03]) -> Option<PointSummary> {
+    let (first, rest) = points.split_first()?;
+    let mut bounds = Lv03BoundingBox::from_point(first);
+    // Sum up the offsets to the first point, this keeps the sums small for large data sets
+    let mut sum_north = 0.0;
+    let mut sum_east = 0.0;
+    let mut sum_altitude = first.altitude.unwrap_or(0.0);
+    let mut altitude_count = usize::from(first.altitude.is_some());
+    for p in rest {
+        bounds.include(p);
+        sum_north += p.north - first.north;
+        sum_east += p.east - first.east;
+        if let Some(altitude) = p.altitude {
+            sum_altitude += altitude;
+            altitude_count += 1;
+        }
+    }
+    let count = points.len();
+    let centroid = Lv03 {
+        north: first.north + sum_north / count as f64,
+        east: first.east + sum_east / count as f64,
+        altitude: (altitude_count > 0).then(|| sum_altitude / altitude_count as f64),
+    };
+    Some(PointSummary {
+        bounds,
+        centroid,
+        count,
+    })
+}
+
+/// Number of points in each square grid cell of the given size, keyed by
+/// `(east_cell, north_cell)`. The cell indices are the coordinates divided by the cell size and
+/// rounded down, like in `Lv03::cell_id`. Cells without points are not contained.
+/// `cell_size_m` must be positive
+#[cfg(feature = "alloc")]
+pub fn bin_points(points: &[Lv03], cell_size_m: f64) -> BTreeMap<(i64, i64), u32> {
+    let mut bins = BTreeMap::new();
+    for p in points {
+        let east_cell = crate::floor(p.east / cell_size_m) as i64;
+        let north_cell = crate::floor(p.north / cell_size_m) as i64;
+        *bins.entry((east_cell, north_cell)).or_insert(0) += 1;
+    }
+    bins
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summarize() {
+        let points = [
+            Lv03::new(200_000.0, 600_000.0, 500.0).unwrap(),
+            Lv03::new(210_000.0, 590_000.0, 700.0).unwrap(),
+            Lv03::new_2d(190_000.0, 620_000.0).unwrap(),
+        ];
+        let summary = summarize(&points).unwrap();
+        assert_eq!(3, summary.count);
+        assert_eq!(
+            Lv03BoundingBox::from_points(&points).unwrap(),
+            summary.bounds
+        );
+        assert_eq!(
+            Lv03::new(200_000.0, 603_333.3333333334, 600.0).unwrap(),
+            summary.centroid
+        );
+        assert_eq!(None, summarize(&[]));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_bin_points() {
+        let points = [
+            Lv03::new(200_010.0, 600_010.0, 500.0).unwrap(),
+            Lv03::new(200_990.0, 600_500.0, 500.0).unwrap(),
+            Lv03::new_2d(200_000.0, 600_999.9).unwrap(),
+            Lv03::new(201_500.0, 602_000.0, 500.0).unwrap(),
+            Lv03::new(201_001.0, 602_999.0, 500.0).unwrap(),
+        ];
+        let bins = bin_points(&points, 1000.0);
+        assert_eq!(2, bins.len());
+        assert_eq!(Some(&3), bins.get(&(600, 200)));
+        assert_eq!(Some(&2), bins.get(&(602, 201)));
+
+        let cell_id = points[0].cell_id(1000);
+        assert_eq!(
+            (600, 200),
+            ((cell_id & 0xffff_ffff) as i64, (cell_id >> 32) as i64)
+        );
+        assert!(bin_points(&[], 1000.0).is_empty());
+    }
+}
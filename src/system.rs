@@ -0,0 +1,68 @@
+use crate::{Lv03, Lv95, Wgs84};
+
+/// Common interface of the swiss coordinate systems, for code which works with any of them.
+/// The methods behave like the inherent conversions of the implementing types
+pub trait CoordinateSystem: Sized {
+    fn to_wgs84(&self) -> Wgs84;
+
+    /// None if the position lies outside of the coordinate system
+    fn from_wgs84(w: &Wgs84) -> Option<Self>;
+}
+
+impl CoordinateSystem for Lv03 {
+    fn to_wgs84(&self) -> Wgs84 {
+        Lv03::to_wgs84(self)
+    }
+
+    fn from_wgs84(w: &Wgs84) -> Option<Self> {
+        w.to_lv03()
+    }
+}
+
+impl CoordinateSystem for Lv95 {
+    fn to_wgs84(&self) -> Wgs84 {
+        Lv95::to_wgs84(self)
+    }
+
+    fn from_wgs84(w: &Wgs84) -> Option<Self> {
+        w.to_lv95()
+    }
+}
+
+/// Converts between two coordinate systems by way of WGS84
+pub fn reproject<A: CoordinateSystem, B: CoordinateSystem>(a: &A) -> Option<B> {
+    B::from_wgs84(&a.to_wgs84())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roundtrip<C: CoordinateSystem>(p: &C) -> Option<C> {
+        C::from_wgs84(&p.to_wgs84())
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let lv03 = Lv03::new(199_498.43, 600_421.43, 542.8).unwrap();
+        let lv03_roundtrip = roundtrip(&lv03).unwrap();
+        assert!(lv03_roundtrip.distance_squared(&lv03) < 1.0);
+
+        let lv95 = Lv95::new(1_199_498.43, 2_600_421.43, 542.8).unwrap();
+        let lv95_roundtrip = roundtrip(&lv95).unwrap();
+        assert!(lv95_roundtrip.distance_squared(&lv95) < 1.0);
+    }
+
+    #[test]
+    fn test_reproject() {
+        let lv03 = Lv03::new(199_498.43, 600_421.43, 542.8).unwrap();
+        let lv95: Lv95 = reproject(&lv03).unwrap();
+        assert!(lv95.distance_squared(&lv03.clone().into()) < 1.0);
+        let outside = Lv03 {
+            north: 200_000.0,
+            east: 1_200_000.0,
+            altitude: None,
+        };
+        assert_eq!(None, reproject::<Lv03, Lv95>(&outside));
+    }
+}
@@ -0,0 +1,415 @@
+use crate::Lv03;
+#[cfg(all(feature = "alloc", feature = "libm"))]
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+
+/// Altitude at the given horizontal distance along a track, linearly interpolated between the
+/// neighboring points. Returns none if the distance is negative, beyond the end of the track or
+/// if the altitude of the neighboring points is unknown
+#[cfg(feature = "libm")]
+pub fn altitude_at_distance(points: &[Lv03], distance_m: f64) -> Option<f64> {
+    if distance_m < 0.0 {
+        return None;
+    }
+    if let [p] = points {
+        return if distance_m == 0.0 { p.altitude } else { None };
+    }
+    let mut travelled = 0.0;
+    for segment in points.windows(2) {
+        let length = segment[0].distance_2d(&segment[1]);
+        if distance_m <= travelled + length {
+            let t = if length > 0.0 {
+                (distance_m - travelled) / length
+            } else {
+                0.0
+            };
+            return segment[0].lerp(&segment[1], t).altitude;
+        }
+        travelled += length;
+    }
+    None
+}
+
+/// Resamples the track with points every `interval_m` meters of horizontal distance along
+/// it, interpolated linearly like in `altitude_at_distance`. The first and the last point of
+/// the track are always included, so the last interval may be shorter. Returns the points
+/// unchanged if the interval is not positive
+#[cfg(all(feature = "alloc", feature = "libm"))]
+pub fn resample_by_distance(points: &[Lv03], interval_m: f64) -> Vec<Lv03> {
+    let (first, last) = match (points.first(), points.last()) {
+        (Some(first), Some(last)) if interval_m > 0.0 => (first, last),
+        _ => return points.to_vec(),
+    };
+    let mut resampled = Vec::new();
+    resampled.push(first.clone());
+    let mut travelled = 0.0;
+    let mut count = 1.0;
+    for segment in points.windows(2) {
+        let length = segment[0].distance_2d(&segment[1]);
+        while count * interval_m < travelled + length {
+            let t = (count * interval_m - travelled) / length;
+            resampled.push(segment[0].lerp(&segment[1], t));
+            count += 1.0;
+        }
+        travelled += length;
+    }
+    if points.len() > 1 {
+        resampled.push(last.clone());
+    }
+    resampled
+}
+
+/// Total ascent and descent `(ascent_m, descent_m)` along a track, both positive.
+/// Points without an altitude are skipped
+pub fn elevation_profile(points: &[Lv03]) -> (f64, f64) {
+    elevation_profile_with_threshold(points, 0.0)
+}
+
+/// Same as `elevation_profile`, but altitude changes are only counted once they exceed the
+/// threshold compared to the last counted altitude. This ignores GPS altitude noise of a
+/// few meters, which otherwise adds up to a considerable ascent on flat tracks
+pub fn elevation_profile_with_threshold(points: &[Lv03], threshold_m: f64) -> (f64, f64) {
+    let mut altitudes = points.iter().filter_map(|p| p.altitude);
+    let mut reference = match altitudes.next() {
+        Some(altitude) => altitude,
+        None => return (0.0, 0.0),
+    };
+    let mut ascent = 0.0;
+    let mut descent = 0.0;
+    for altitude in altitudes {
+        let difference = altitude - reference;
+        if difference > threshold_m {
+            ascent += difference;
+            reference = altitude;
+        } else if difference < -threshold_m {
+            descent -= difference;
+            reference = altitude;
+        }
+    }
+    (ascent, descent)
+}
+
+/// Gradient in percent of each segment between consecutive points, i.e. 100 times the change
+/// in altitude divided by the horizontal distance. Positive uphill and negative downhill.
+/// Segments without length or with a point without altitude have a gradient of 0
+#[cfg(all(feature = "alloc", feature = "libm"))]
+pub fn segment_gradients(points: &[Lv03]) -> Vec<f64> {
+    points
+        .windows(2)
+        .map(|segment| {
+            let length = segment[0].distance_2d(&segment[1]);
+            match (segment[0].altitude, segment[1].altitude) {
+                (Some(from), Some(to)) if length > 0.0 => 100.0 * (to - from) / length,
+                _ => 0.0,
+            }
+        })
+        .collect()
+}
+
+/// Horizontal speed in m/s of each segment between consecutive `(point, timestamp_s)` pairs.
+/// Segments where the time does not advance have a speed of 0
+#[cfg(all(feature = "alloc", feature = "libm"))]
+pub fn speeds(track: &[(Lv03, f64)]) -> Vec<f64> {
+    track
+        .windows(2)
+        .map(|segment| {
+            let (from, from_time) = &segment[0];
+            let (to, to_time) = &segment[1];
+            let duration = to_time - from_time;
+            if duration > 0.0 {
+                from.distance_2d(to) / duration
+            } else {
+                0.0
+            }
+        })
+        .collect()
+}
+
+/// Sum of the absolute changes of the grid bearing in degrees at the interior points of the
+/// track, a measure for how winding it is. Each change counts 0 to 180 degrees regardless of
+/// the direction of the turn. Repeated points are skipped, 0 for fewer than three points
+#[cfg(feature = "libm")]
+pub fn total_turning(points: &[Lv03]) -> f64 {
+    let mut bearings = points
+        .windows(2)
+        .filter(|segment| {
+            segment[0].north != segment[1].north || segment[0].east != segment[1].east
+        })
+        .map(|segment| segment[0].bearing_to(&segment[1]));
+    let mut previous = match bearings.next() {
+        Some(bearing) => bearing,
+        None => return 0.0,
+    };
+    let mut total = 0.0;
+    for bearing in bearings {
+        let change = (bearing - previous).abs();
+        total += if change > 180.0 {
+            360.0 - change
+        } else {
+            change
+        };
+        previous = bearing;
+    }
+    total
+}
+
+/// Running altitude statistics of a live track without storing the points, the streaming
+/// counterpart of `elevation_profile`. Points without an altitude are ignored
+#[derive(Clone, Debug, PartialEq)]
+pub struct AltitudeTracker {
+    min: f64,
+    max: f64,
+    last: Option<f64>,
+    ascent: f64,
+    descent: f64,
+}
+
+impl AltitudeTracker {
+    pub fn new() -> Self {
+        AltitudeTracker {
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+            last: None,
+            ascent: 0.0,
+            descent: 0.0,
+        }
+    }
+
+    /// Updates the statistics with the next point of the track
+    pub fn push(&mut self, p: &Lv03) {
+        let altitude = match p.altitude {
+            Some(altitude) => altitude,
+            None => return,
+        };
+        self.min = self.min.min(altitude);
+        self.max = self.max.max(altitude);
+        if let Some(last) = self.last {
+            if altitude > last {
+                self.ascent += altitude - last;
+            } else {
+                self.descent += last - altitude;
+            }
+        }
+        self.last = Some(altitude);
+    }
+
+    /// Lowest altitude so far, none if no point had an altitude
+    pub fn min(&self) -> Option<f64> {
+        self.last.map(|_| self.min)
+    }
+
+    /// Highest altitude so far, none if no point had an altitude
+    pub fn max(&self) -> Option<f64> {
+        self.last.map(|_| self.max)
+    }
+
+    /// Most recent altitude
+    pub fn last(&self) -> Option<f64> {
+        self.last
+    }
+
+    /// Total ascent so far in meters
+    pub fn ascent(&self) -> f64 {
+        self.ascent
+    }
+
+    /// Total descent so far in meters, positive
+    pub fn descent(&self) -> f64 {
+        self.descent
+    }
+}
+
+impl Default for AltitudeTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Points with an altitude within the range, see `Lv03::altitude_in_range`
+pub fn filter_by_altitude(
+    points: &[Lv03],
+    range: RangeInclusive<f64>,
+) -> impl Iterator<Item = &Lv03> {
+    points
+        .iter()
+        .filter(move |p| p.altitude_in_range(range.clone()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "libm")]
+    fn test_altitude_at_distance() {
+        let track = [
+            Lv03::new(200_000.0, 600_000.0, 500.0).unwrap(),
+            Lv03::new(200_000.0, 600_300.0, 800.0).unwrap(),
+            Lv03::new(200_400.0, 600_300.0, 600.0).unwrap(),
+        ];
+        assert_eq!(Some(500.0), altitude_at_distance(&track, 0.0));
+        assert_eq!(Some(600.0), altitude_at_distance(&track, 100.0));
+        assert_eq!(Some(800.0), altitude_at_distance(&track, 300.0));
+        assert_eq!(Some(700.0), altitude_at_distance(&track, 500.0));
+        assert_eq!(Some(600.0), altitude_at_distance(&track, 700.0));
+        assert_eq!(None, altitude_at_distance(&track, 700.1));
+        assert_eq!(None, altitude_at_distance(&track, -1.0));
+        assert_eq!(None, altitude_at_distance(&[], 0.0));
+        assert_eq!(Some(500.0), altitude_at_distance(&track[..1], 0.0));
+    }
+
+    #[test]
+    #[cfg(feature = "libm")]
+    fn test_total_turning() {
+        let straight = [
+            Lv03::new_2d(200_000.0, 600_000.0).unwrap(),
+            Lv03::new_2d(200_100.0, 600_100.0).unwrap(),
+            Lv03::new_2d(200_100.0, 600_100.0).unwrap(),
+            Lv03::new_2d(200_300.0, 600_300.0).unwrap(),
+        ];
+        assert!(total_turning(&straight).abs() < 1e-9);
+
+        // Alternating between north east and south east, 90 degrees at each interior point
+        let zigzag = [
+            Lv03::new_2d(200_000.0, 600_000.0).unwrap(),
+            Lv03::new_2d(200_100.0, 600_100.0).unwrap(),
+            Lv03::new_2d(200_000.0, 600_200.0).unwrap(),
+            Lv03::new_2d(200_100.0, 600_300.0).unwrap(),
+            Lv03::new_2d(200_000.0, 600_400.0).unwrap(),
+        ];
+        assert!((total_turning(&zigzag) - 270.0).abs() < 1e-9);
+
+        // Turning through north counts the smaller angle
+        let across_north = [
+            Lv03::new_2d(200_000.0, 600_100.0).unwrap(),
+            Lv03::new_2d(200_100.0, 600_000.0).unwrap(),
+            Lv03::new_2d(200_200.0, 600_100.0).unwrap(),
+        ];
+        assert!((total_turning(&across_north) - 90.0).abs() < 1e-9);
+        assert_eq!(0.0, total_turning(&zigzag[..2]));
+    }
+
+    #[test]
+    fn test_filter_by_altitude() {
+        let points = [
+            Lv03::new(200_000.0, 600_000.0, 500.0).unwrap(),
+            Lv03::new(200_000.0, 600_300.0, 1800.0).unwrap(),
+            Lv03::new_2d(200_400.0, 600_300.0).unwrap(),
+            Lv03::new(200_400.0, 600_600.0, 2000.0).unwrap(),
+        ];
+        let mut filtered = filter_by_altitude(&points, 1800.0..=2000.0);
+        assert_eq!(Some(&points[1]), filtered.next());
+        assert_eq!(Some(&points[3]), filtered.next());
+        assert_eq!(None, filtered.next());
+        assert!(!points[2].altitude_in_range(f64::MIN..=f64::MAX));
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "libm"))]
+    fn test_segment_gradients() {
+        let track = [
+            Lv03::new(200_000.0, 600_000.0, 500.0).unwrap(),
+            Lv03::new(200_000.0, 600_400.0, 530.0).unwrap(),
+            Lv03::new(200_300.0, 600_800.0, 505.0).unwrap(),
+            Lv03::new(200_300.0, 600_800.0, 600.0).unwrap(),
+            Lv03::new_2d(200_400.0, 600_800.0).unwrap(),
+        ];
+        assert_eq!([7.5, -5.0, 0.0, 0.0][..], segment_gradients(&track)[..]);
+        assert!(segment_gradients(&track[..1]).is_empty());
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "libm"))]
+    fn test_speeds() {
+        let track = [
+            (Lv03::new_2d(200_000.0, 600_000.0).unwrap(), 0.0),
+            (Lv03::new_2d(200_030.0, 600_040.0).unwrap(), 10.0),
+            (Lv03::new_2d(200_060.0, 600_080.0).unwrap(), 20.0),
+            (Lv03::new_2d(200_090.0, 600_120.0).unwrap(), 20.0),
+            (Lv03::new_2d(200_090.0, 600_120.0).unwrap(), 15.0),
+        ];
+        assert_eq!([5.0, 5.0, 0.0, 0.0][..], speeds(&track)[..]);
+        assert!(speeds(&track[..1]).is_empty());
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "libm"))]
+    fn test_resample_by_distance() {
+        let track = [
+            Lv03::new(200_000.0, 600_000.0, 500.0).unwrap(),
+            Lv03::new(200_000.0, 600_300.0, 800.0).unwrap(),
+            Lv03::new(200_250.0, 600_300.0, 550.0).unwrap(),
+        ];
+        let resampled = resample_by_distance(&track, 200.0);
+        let expected = [
+            track[0].clone(),
+            Lv03::new(200_000.0, 600_200.0, 700.0).unwrap(),
+            Lv03::new(200_100.0, 600_300.0, 700.0).unwrap(),
+            track[2].clone(),
+        ];
+        assert_eq!(expected[..], resampled[..]);
+
+        // The end lies exactly on the interval
+        let resampled = resample_by_distance(&track, 275.0);
+        assert_eq!(3, resampled.len());
+        assert_eq!(
+            Lv03::new(200_000.0, 600_275.0, 775.0),
+            Some(resampled[1].clone())
+        );
+
+        assert_eq!(track[..], resample_by_distance(&track, 0.0)[..]);
+        assert_eq!(track[..1], resample_by_distance(&track[..1], 10.0)[..]);
+        assert!(resample_by_distance(&[], 10.0).is_empty());
+    }
+
+    #[test]
+    fn test_elevation_profile() {
+        let altitudes = [500.0, 520.0, 518.0, 600.0, 450.0, 452.0, 470.0];
+        let track = altitudes.map(|altitude| Lv03::new(200_000.0, 600_000.0, altitude).unwrap());
+        assert_eq!((122.0, 152.0), elevation_profile(&track));
+        // The 2 m changes are ignored
+        assert_eq!(
+            (120.0, 150.0),
+            elevation_profile_with_threshold(&track, 5.0)
+        );
+        assert_eq!(
+            (100.0, 150.0),
+            elevation_profile_with_threshold(&track, 60.0)
+        );
+
+        let track = [
+            Lv03::new(200_000.0, 600_000.0, 500.0).unwrap(),
+            Lv03::new_2d(200_000.0, 600_000.0).unwrap(),
+            Lv03::new(200_000.0, 600_000.0, 400.0).unwrap(),
+        ];
+        assert_eq!((0.0, 100.0), elevation_profile(&track));
+        assert_eq!((0.0, 0.0), elevation_profile(&[]));
+    }
+
+    #[test]
+    fn test_altitude_tracker() {
+        let mut tracker = AltitudeTracker::new();
+        assert_eq!(None, tracker.min());
+        assert_eq!(None, tracker.max());
+        assert_eq!(0.0, tracker.ascent());
+
+        let track = [
+            Lv03::new(200_000.0, 600_000.0, 500.0).unwrap(),
+            Lv03::new(200_000.0, 600_000.0, 520.0).unwrap(),
+            Lv03::new_2d(200_000.0, 600_000.0).unwrap(),
+            Lv03::new(200_000.0, 600_000.0, 600.0).unwrap(),
+            Lv03::new(200_000.0, 600_000.0, 450.0).unwrap(),
+            Lv03::new(200_000.0, 600_000.0, 470.0).unwrap(),
+        ];
+        for p in &track {
+            tracker.push(p);
+        }
+        assert_eq!(Some(450.0), tracker.min());
+        assert_eq!(Some(600.0), tracker.max());
+        assert_eq!(Some(470.0), tracker.last());
+        assert_eq!(
+            elevation_profile(&track),
+            (tracker.ascent(), tracker.descent())
+        );
+    }
+}
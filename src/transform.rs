@@ -0,0 +1,179 @@
+//! Planar transformations of points on the LV03 grid. The results are not validated
+
+use crate::Lv03;
+#[cfg(all(feature = "libm", feature = "rand_core"))]
+use rand_core::RngCore;
+
+impl Lv03 {
+    /// Rotates the point counter-clockwise by `angle_deg` degrees around the pivot.
+    /// The altitude is unchanged
+    #[cfg(feature = "libm")]
+    pub fn rotate_about(&self, pivot: &Lv03, angle_deg: f64) -> Lv03 {
+        let (sin, cos) = libm::sincos(angle_deg.to_radians());
+        let d_east = self.east - pivot.east;
+        let d_north = self.north - pivot.north;
+        Lv03 {
+            north: pivot.north + sin * d_east + cos * d_north,
+            east: pivot.east + cos * d_east - sin * d_north,
+            altitude: self.altitude,
+        }
+    }
+
+    /// Moves the point `distance_m` meters on the grid in the direction of the bearing
+    /// (degrees clockwise from grid north). The altitude is unchanged
+    #[cfg(feature = "libm")]
+    pub fn offset_by(&self, bearing_deg: f64, distance_m: f64) -> Lv03 {
+        let (sin, cos) = libm::sincos(bearing_deg.to_radians());
+        Lv03 {
+            north: self.north + distance_m * cos,
+            east: self.east + distance_m * sin,
+            altitude: self.altitude,
+        }
+    }
+
+    /// Point `distance_m` meters away in the direction of the sun at the given azimuth (degrees
+    /// clockwise from north) and elevation above the horizon (degrees), e.g. to check whether
+    /// terrain in between casts a shadow. The azimuth is applied on the grid, the difference to
+    /// true north of at most about 2° is neglected. The altitude is none if it is unknown
+    #[cfg(feature = "libm")]
+    pub fn sun_offset(&self, azimuth_deg: f64, altitude_deg: f64, distance_m: f64) -> Lv03 {
+        let (sin, cos) = libm::sincos(altitude_deg.to_radians());
+        let mut p = self.offset_by(azimuth_deg, distance_m * cos);
+        p.altitude = self.altitude.map(|altitude| altitude + distance_m * sin);
+        p
+    }
+
+    /// Moves the point by a random offset, uniformly distributed within a disk of radius
+    /// `max_offset_m` around it. Used to obfuscate exact locations before publishing them.
+    /// The altitude is unchanged
+    #[cfg(all(feature = "libm", feature = "rand_core"))]
+    pub fn jitter(&self, max_offset_m: f64, rng: &mut impl RngCore) -> Lv03 {
+        // Uniform in [0, 1) from the upper 53 bits
+        let mut uniform = || (rng.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        // The square root gives a uniform density over the area of the disk
+        let radius = max_offset_m * libm::sqrt(uniform());
+        let (sin, cos) = libm::sincos(2.0 * core::f64::consts::PI * uniform());
+        Lv03 {
+            north: self.north + radius * cos,
+            east: self.east + radius * sin,
+            altitude: self.altitude,
+        }
+    }
+
+    /// Applies the 2D affine transformation `[a, b, c, d, e, f]` with
+    /// `east' = a * east + b * north + c` and `north' = d * east + e * north + f`.
+    /// The altitude is unchanged
+    pub fn apply_affine(&self, matrix: [f64; 6]) -> Lv03 {
+        let [a, b, c, d, e, f] = matrix;
+        Lv03 {
+            north: d * self.east + e * self.north + f,
+            east: a * self.east + b * self.north + c,
+            altitude: self.altitude,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "libm")]
+    fn test_offset_by() {
+        let p = Lv03::new(200_000.0, 600_000.0, 500.0).unwrap();
+        let moved = p.offset_by(90.0, 1000.0);
+        assert!((moved.north - 200_000.0).abs() < 1e-9);
+        assert!((moved.east - 601_000.0).abs() < 1e-9);
+        assert_eq!(Some(500.0), moved.altitude);
+        let moved = p.offset_by(225.0, 2f64.sqrt() * 1000.0);
+        assert!((moved.north - 199_000.0).abs() < 1e-9);
+        assert!((moved.east - 599_000.0).abs() < 1e-9);
+    }
+
+    #[test]
+    #[cfg(feature = "libm")]
+    fn test_sun_offset() {
+        let p = Lv03::new(200_000.0, 600_000.0, 500.0).unwrap();
+        // Sun in the south at 45° elevation
+        let distance = 2f64.sqrt() * 1000.0;
+        let towards_sun = p.sun_offset(180.0, 45.0, distance);
+        assert!((towards_sun.north - 199_000.0).abs() < 1e-9);
+        assert!((towards_sun.east - 600_000.0).abs() < 1e-9);
+        assert!((towards_sun.altitude.unwrap() - 1500.0).abs() < 1e-9);
+
+        let p = Lv03::new_2d(200_000.0, 600_000.0).unwrap();
+        assert_eq!(None, p.sun_offset(180.0, 45.0, distance).altitude);
+    }
+
+    #[test]
+    #[cfg(feature = "libm")]
+    fn test_rotate_about() {
+        let pivot = Lv03::new(200_000.0, 600_000.0, 500.0).unwrap();
+        let p = Lv03::new(200_000.0, 601_000.0, 700.0).unwrap();
+
+        let rotated = p.rotate_about(&pivot, 90.0);
+        assert!((rotated.north - 201_000.0).abs() < 1e-9);
+        assert!((rotated.east - 600_000.0).abs() < 1e-9);
+        assert_eq!(Some(700.0), rotated.altitude);
+
+        let rotated = p.rotate_about(&pivot, -90.0);
+        assert!((rotated.north - 199_000.0).abs() < 1e-9);
+        assert!((rotated.east - 600_000.0).abs() < 1e-9);
+
+        assert!(p.rotate_about(&pivot, 360.0).distance_squared(&p) < 1e-12);
+        assert_eq!(pivot, pivot.rotate_about(&pivot, 33.0));
+    }
+
+    #[test]
+    fn test_apply_affine() {
+        let p = Lv03::new(199_498.43, 600_421.43, 542.8).unwrap();
+        assert_eq!(p, p.apply_affine([1.0, 0.0, 0.0, 0.0, 1.0, 0.0]));
+
+        let shifted = p.apply_affine([1.0, 0.0, 2_000_000.0, 0.0, 1.0, 1_000_000.0]);
+        assert_eq!(2_600_421.43, shifted.east);
+        assert_eq!(1_199_498.43, shifted.north);
+
+        // Swap the axes
+        let swapped = p.apply_affine([0.0, 1.0, 0.0, 1.0, 0.0, 0.0]);
+        assert_eq!(p.north, swapped.east);
+        assert_eq!(p.east, swapped.north);
+        assert_eq!(p.altitude, swapped.altitude);
+    }
+
+    /// Xorshift generator for reproducible tests
+    #[cfg(all(feature = "libm", feature = "rand_core"))]
+    struct TestRng(u64);
+
+    #[cfg(all(feature = "libm", feature = "rand_core"))]
+    impl RngCore for TestRng {
+        fn next_u32(&mut self) -> u32 {
+            (self.next_u64() >> 32) as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            rand_core::impls::fill_bytes_via_next(self, dst)
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "libm", feature = "rand_core"))]
+    fn test_jitter() {
+        let mut rng = TestRng(0x2545_f491_4f6c_dd1d);
+        let p = Lv03::new(199_498.43, 600_421.43, 542.8).unwrap();
+        let mut max_distance: f64 = 0.0;
+        for _ in 0..1000 {
+            let jittered = p.jitter(100.0, &mut rng);
+            assert_eq!(p.altitude, jittered.altitude);
+            max_distance = max_distance.max(p.distance_2d(&jittered));
+        }
+        assert!(max_distance <= 100.0);
+        assert!(max_distance > 90.0);
+    }
+}
@@ -0,0 +1,68 @@
+//! WGS84 Pseudo-Mercator (EPSG:3857) as used by the tiles of most web maps
+
+use crate::Wgs84;
+use core::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+/// Radius of the sphere used by the projection, the WGS84 semi-major axis
+const RADIUS: f64 = 6_378_137.0;
+/// Latitude in degrees at which the projected map becomes square
+const MAX_LATITUDE: f64 = 85.051_128_779_806_59;
+
+impl Wgs84 {
+    /// Projected `(x, y)` in meters in EPSG:3857. The latitude is clamped to ±85.05°, the limit
+    /// of the square web map. The altitude is dropped
+    pub fn to_web_mercator(&self) -> (f64, f64) {
+        let latitude = self
+            .latitude
+            .clamp(-MAX_LATITUDE, MAX_LATITUDE)
+            .to_radians();
+        let x = RADIUS * self.longitude_rad();
+        let y = RADIUS * libm::log(libm::tan(FRAC_PI_4 + latitude / 2.0));
+        (x, y)
+    }
+
+    /// Inverse of `to_web_mercator`, the position has no altitude
+    pub fn from_web_mercator(x: f64, y: f64) -> Wgs84 {
+        let latitude = 2.0 * libm::atan(libm::exp(y / RADIUS)) - FRAC_PI_2;
+        Wgs84 {
+            longitude: (x / RADIUS).to_degrees(),
+            latitude: latitude.to_degrees(),
+            altitude: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bern() {
+        let bern = Wgs84 {
+            longitude: 7.43863,
+            latitude: 46.95108,
+            altitude: Some(560.0),
+        };
+        let (x, y) = bern.to_web_mercator();
+        assert!((x - 828_064.50).abs() < 0.01);
+        assert!((y - 5_934_092.74).abs() < 0.01);
+
+        let back = Wgs84::from_web_mercator(x, y);
+        assert!((back.longitude - bern.longitude).abs() < 1e-12);
+        assert!((back.latitude - bern.latitude).abs() < 1e-12);
+        assert_eq!(None, back.altitude);
+    }
+
+    #[test]
+    fn test_clamped_latitude() {
+        let pole = Wgs84 {
+            longitude: 180.0,
+            latitude: 90.0,
+            altitude: None,
+        };
+        let (x, y) = pole.to_web_mercator();
+        let half_extent = RADIUS * core::f64::consts::PI;
+        assert!((x - half_extent).abs() < 1e-6);
+        assert!((y - half_extent).abs() < 1e-6);
+    }
+}
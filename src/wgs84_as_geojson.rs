@@ -0,0 +1,97 @@
+//! Serializes a `Wgs84` as GeoJSON position, i.e. as array `[longitude, latitude]` or
+//! `[longitude, latitude, altitude]` if the altitude is known.
+//!
+//! Use it with `#[serde(with = "lv03::wgs84_as_geojson")]` on a `Wgs84` field.
+
+use crate::Wgs84;
+use core::fmt;
+use serde::de::{Error, SeqAccess, Visitor};
+use serde::ser::SerializeSeq;
+use serde::{Deserializer, Serializer};
+
+pub fn serialize<S: Serializer>(p: &Wgs84, serializer: S) -> Result<S::Ok, S::Error> {
+    let len = if p.altitude.is_some() { 3 } else { 2 };
+    let mut seq = serializer.serialize_seq(Some(len))?;
+    seq.serialize_element(&p.longitude)?;
+    seq.serialize_element(&p.latitude)?;
+    if let Some(altitude) = p.altitude {
+        seq.serialize_element(&altitude)?;
+    }
+    seq.end()
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Wgs84, D::Error> {
+    deserializer.deserialize_seq(PositionVisitor)
+}
+
+struct PositionVisitor;
+
+impl<'de> Visitor<'de> for PositionVisitor {
+    type Value = Wgs84;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a GeoJSON position [longitude, latitude] or [longitude, latitude, altitude]")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Wgs84, A::Error> {
+        let longitude = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        let latitude = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(1, &self))?;
+        let altitude = seq.next_element()?;
+        if seq.next_element::<f64>()?.is_some() {
+            return Err(Error::invalid_length(4, &self));
+        }
+        Ok(Wgs84 {
+            longitude,
+            latitude,
+            altitude,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_json(p: &Wgs84) -> serde_json::Value {
+        serialize(p, serde_json::value::Serializer).unwrap()
+    }
+
+    fn from_json(json: &str) -> serde_json::Result<Wgs84> {
+        deserialize(&mut serde_json::Deserializer::from_str(json))
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let wgs = Wgs84 {
+            longitude: 7.44417,
+            latitude: 46.94658,
+            altitude: Some(591.8),
+        };
+        let json = to_json(&wgs);
+        assert_eq!(serde_json::json!([7.44417, 46.94658, 591.8]), json);
+        assert_eq!(wgs, deserialize(json).unwrap());
+    }
+
+    #[test]
+    fn test_without_altitude() {
+        let wgs = Wgs84 {
+            longitude: 7.44417,
+            latitude: 46.94658,
+            altitude: None,
+        };
+        let json = to_json(&wgs);
+        assert_eq!(serde_json::json!([7.44417, 46.94658]), json);
+        assert_eq!(wgs, deserialize(json).unwrap());
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(from_json("[7.44417]").is_err());
+        assert!(from_json("[7.44417, 46.94658, 591.8, 1.0]").is_err());
+        assert!(from_json("{\"longitude\": 7.44417}").is_err());
+    }
+}
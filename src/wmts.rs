@@ -0,0 +1,53 @@
+//! Tile positions in the WMTS service of swisstopo for the LV95 tile matrix set (EPSG:2056)
+
+use crate::Lv95;
+
+/// Top left corner of the tile matrices as `(east, north)`
+const ORIGIN: (f64, f64) = (2_420_000.0, 1_350_000.0);
+
+/// Width and height of a tile in pixels
+const TILE_SIZE: f64 = 256.0;
+
+/// Meters per pixel of the zoom levels 0 to 28
+const RESOLUTIONS: [f64; 29] = [
+    4000.0, 3750.0, 3500.0, 3250.0, 3000.0, 2750.0, 2500.0, 2250.0, 2000.0, 1750.0, 1500.0, 1250.0,
+    1000.0, 750.0, 650.0, 500.0, 250.0, 100.0, 50.0, 20.0, 10.0, 5.0, 2.5, 2.0, 1.5, 1.0, 0.5,
+    0.25, 0.1,
+];
+
+impl Lv95 {
+    /// Position of the point in the swisstopo WMTS tile matrix of the given zoom level as
+    /// `(tile_col, tile_row, px_x, px_y)`, assuming tiles of 256 by 256 pixels. Columns and
+    /// pixels count to the east, rows and pixels to the south from the top left corner of the
+    /// matrix at E 2420000 / N 1350000. Returns none for zoom levels above 28
+    pub fn to_wmts_pixel(&self, zoom: u8) -> Option<(u32, u32, u16, u16)> {
+        let resolution = RESOLUTIONS.get(usize::from(zoom))?;
+        // Points within the bounds are always to the south east of the origin
+        let x = ((self.east - ORIGIN.0) / resolution) as u32;
+        let y = ((ORIGIN.1 - self.north) / resolution) as u32;
+        let tile_size = TILE_SIZE as u32;
+        Some((
+            x / tile_size,
+            y / tile_size,
+            (x % tile_size) as u16,
+            (y % tile_size) as u16,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_wmts_pixel() {
+        let bern = Lv95::new(1_200_000.0, 2_600_000.0, 0.0).unwrap();
+        // 100 m per pixel
+        assert_eq!(Some((7, 5, 8, 220)), bern.to_wmts_pixel(17));
+        // 0.1 m per pixel
+        assert_eq!(Some((7031, 5859, 64, 96)), bern.to_wmts_pixel(28));
+        // The whole country fits into the single tile of the first level
+        assert_eq!(Some((0, 0, 45, 37)), bern.to_wmts_pixel(0));
+        assert_eq!(None, bern.to_wmts_pixel(29));
+    }
+}